@@ -0,0 +1,270 @@
+#![cfg(feature = "std")]
+
+use crate::TryTreelike;
+use alloc::{rc::Rc, vec::Vec};
+use std::{
+	ffi::OsStr,
+	fs::{self, Metadata},
+	io,
+	path::{Path, PathBuf},
+};
+
+/// A directory hierarchy on disk, as a [TryTreelike].
+///
+/// The content of each node is a [DirEntry] with its path and metadata. Children are listed
+/// sorted by name, files are leaves. Everything that can go wrong while reading the file
+/// system is reported as an [io::Error] together with the path of the node inside the tree, see
+/// [try_treelike][crate::try_treelike].
+///
+/// By default symbolic links are not followed and show up as leaves.
+/// [follow_links][DirTree::follow_links] descends into linked directories. Entries that lead
+/// back into one of their own ancestors are reported as errors instead of being followed in
+/// circles. Links that point nowhere are leaves with the metadata of the link itself.
+///
+/// Directories like `target` or `.git` can be left out with
+/// [skip_subtree][crate::try_treelike::TryDFT::skip_subtree] during a pre-order walk.
+///
+/// ```no_run
+/// use treelike::{TryTreelike, adapter::DirTree};
+///
+/// // all rust files in src, stopping at the first error
+/// let mut sources = Vec::new();
+/// DirTree::new("src").try_callback_dft_pre(|entry, _depth| {
+/// 	if entry.path.extension().is_some_and(|e| e == "rs") {
+/// 		sources.push(entry.path);
+/// 	}
+/// })?;
+/// # Ok::<(), treelike::try_treelike::PathError<std::io::Error>>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct DirTree(Rc<DirNode>);
+
+#[derive(Debug)]
+struct DirNode {
+	path: PathBuf,
+	/// where the node really is, only tracked when following links
+	canonical: Option<PathBuf>,
+	parent: Option<DirTree>,
+}
+
+/// A file or directory visited by a [DirTree].
+#[derive(Clone, Debug)]
+pub struct DirEntry {
+	/// The path of the entry, starting with the path of the root.
+	pub path: PathBuf,
+	/// The metadata of the entry, or of the link target when following links that lead somewhere.
+	pub metadata: Metadata,
+}
+
+impl DirEntry {
+	/// The last component of the path.
+	pub fn name(&self) -> Option<&OsStr> { self.path.file_name() }
+}
+
+impl DirTree {
+	/// Creates a tree rooted at path, not following symbolic links.
+	pub fn new<P: AsRef<Path>>(path: P) -> Self {
+		DirTree(Rc::new(DirNode {
+			path: path.as_ref().to_path_buf(),
+			canonical: None,
+			parent: None,
+		}))
+	}
+
+	/// Creates a tree rooted at path that descends into linked directories.
+	///
+	/// Fails if path can not be resolved.
+	pub fn follow_links<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+		let path = path.as_ref();
+		Ok(DirTree(Rc::new(DirNode {
+			path: path.to_path_buf(),
+			canonical: Some(fs::canonicalize(path)?),
+			parent: None,
+		})))
+	}
+
+	/// The path of this node.
+	pub fn path(&self) -> &Path { &self.0.path }
+
+	fn metadata(&self) -> io::Result<Metadata> {
+		match self.0.canonical {
+			Some(_) => fs::metadata(&self.0.path).or_else(|e| dangling(&self.0.path, e)),
+			None => fs::symlink_metadata(&self.0.path),
+		}
+	}
+
+	fn child(&self, entry: io::Result<fs::DirEntry>) -> io::Result<Self> {
+		let entry = entry?;
+		let canonical = match &self.0.canonical {
+			None => None,
+			Some(canonical) => {
+				let own = canonical.join(entry.file_name());
+				let target = if entry.file_type()?.is_symlink() {
+					fs::canonicalize(entry.path())
+						.or_else(|e| dangling(&entry.path(), e).map(|_| own))?
+				} else {
+					own
+				};
+				if self.is_below(&target) {
+					return Err(io::Error::other("symbolic link loop"));
+				}
+				Some(target)
+			},
+		};
+		Ok(DirTree(Rc::new(DirNode {
+			path: entry.path(),
+			canonical,
+			parent: Some(self.clone()),
+		})))
+	}
+
+	/// True if this node or one of its ancestors really is at canonical.
+	fn is_below(&self, canonical: &Path) -> bool {
+		let mut node = Some(self);
+		while let Some(DirTree(n)) = node {
+			if n.canonical.as_deref() == Some(canonical) {
+				return true;
+			}
+			node = n.parent.as_ref();
+		}
+		false
+	}
+}
+
+/// Falls back to the link itself if the error comes from a link pointing nowhere.
+fn dangling(path: &Path, error: io::Error) -> io::Result<Metadata> {
+	match fs::symlink_metadata(path) {
+		Ok(link) if error.kind() == io::ErrorKind::NotFound && link.is_symlink() => Ok(link),
+		_ => Err(error),
+	}
+}
+
+impl TryTreelike for DirTree {
+	type Content = DirEntry;
+
+	type Error = io::Error;
+
+	type ChildIterator = alloc::vec::IntoIter<io::Result<Self>>;
+
+	fn try_content(self) -> io::Result<Self::Content> {
+		Ok(DirEntry {
+			metadata: self.metadata()?,
+			path: self.0.path.clone(),
+		})
+	}
+
+	fn try_children(self) -> io::Result<Self::ChildIterator> {
+		if !self.metadata()?.is_dir() {
+			return Ok(Vec::new().into_iter());
+		}
+		let mut entries: Vec<_> = fs::read_dir(&self.0.path)?.collect();
+		// errors first, they don't have a name
+		entries.sort_by_key(|entry| entry.as_ref().ok().map(fs::DirEntry::file_name));
+		let children: Vec<_> = entries.into_iter().map(|e| self.child(e)).collect();
+		Ok(children.into_iter())
+	}
+}
+
+#[test]
+fn dir_tree_test() {
+	let tmp = tempfile::tempdir().unwrap();
+	let root = tmp.path();
+	fs::create_dir_all(root.join("b/d")).unwrap();
+	fs::write(root.join("c"), "c").unwrap();
+	fs::write(root.join("a"), "a").unwrap();
+	fs::write(root.join("b/d/e"), "e").unwrap();
+
+	let names = |tree: DirTree| -> Vec<_> {
+		tree.try_iter_dft_pre()
+			.map(|entry| {
+				let entry = entry.unwrap();
+				let path = entry.path.strip_prefix(root).unwrap();
+				path.to_string_lossy().replace('\\', "/")
+			})
+			.collect()
+	};
+	assert_eq!(names(DirTree::new(root)), [
+		"", "a", "b", "b/d", "b/d/e", "c"
+	]);
+
+	let mut depths = Vec::new();
+	DirTree::new(root)
+		.try_callback_bft(|entry, depth| {
+			if entry.metadata.is_file() {
+				depths.push(depth)
+			}
+		})
+		.unwrap();
+	assert_eq!(depths, [1, 1, 3]);
+
+	let missing = DirTree::new(root.join("missing")).try_callback_dft(|_, _| {});
+	assert_eq!(missing.unwrap_err().error().kind(), io::ErrorKind::NotFound);
+
+	// pruning b, its contents are never read
+	let mut iter = DirTree::new(root).try_iter_dft_pre();
+	let mut pruned = Vec::new();
+	while let Some(entry) = iter.next() {
+		let entry = entry.unwrap();
+		if entry.name() == Some("b".as_ref()) {
+			fs::remove_dir_all(&entry.path).unwrap();
+			iter.skip_subtree();
+		}
+		pruned.push(entry.path);
+	}
+	assert_eq!(pruned, [
+		root.into(),
+		root.join("a"),
+		root.join("b"),
+		root.join("c")
+	]);
+}
+
+#[cfg(unix)]
+#[test]
+fn dir_tree_links() {
+	use std::os::unix::fs::symlink;
+
+	let tmp = tempfile::tempdir().unwrap();
+	let root = tmp.path();
+	fs::create_dir_all(root.join("a/b")).unwrap();
+	fs::write(root.join("a/b/file"), "").unwrap();
+	symlink(root.join("a/b"), root.join("link")).unwrap();
+	symlink(root.join("a"), root.join("a/b/up")).unwrap();
+	symlink(root.join("missing"), root.join("nowhere")).unwrap();
+
+	// not following, links are leaves
+	assert_eq!(DirTree::new(root).try_iter_dft().count(), 7);
+
+	// following, each way back into a directory that is already being visited is an error
+	let followed = DirTree::follow_links(root).unwrap();
+	let (ok, err): (Vec<_>, Vec<_>) = followed.try_iter_dft_pre().partition(Result::is_ok);
+	let paths: Vec<_> = ok
+		.into_iter()
+		.map(|entry| {
+			entry
+				.unwrap()
+				.path
+				.strip_prefix(root)
+				.unwrap()
+				.to_path_buf()
+		})
+		.collect();
+	assert_eq!(paths, [
+		PathBuf::new(),
+		"a".into(),
+		"a/b".into(),
+		"a/b/file".into(),
+		"link".into(),
+		"link/file".into(),
+		"link/up".into(),
+		"nowhere".into(),
+	]);
+	let err: Vec<_> = err
+		.into_iter()
+		.map(|e| e.unwrap_err().path().clone())
+		.collect();
+	assert_eq!(err, [
+		crate::path::TreePath::from(vec![0, 0, 1]),
+		crate::path::TreePath::from(vec![1, 1, 0]),
+	]);
+}
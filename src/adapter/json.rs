@@ -0,0 +1,168 @@
+#![cfg(feature = "serde_json")]
+
+use super::{Key, Pointers};
+use crate::{Treelike, treelike::FilterBuilder};
+use core::{iter::Enumerate, slice};
+use serde_json::{Value, map};
+
+/// A node of a [serde_json] document.
+///
+/// The content is the [Key] the node is stored under and its [Value]. For arrays and objects
+/// that is the whole container, the elements follow as children. Object members are visited in
+/// the order of the [Map][serde_json::Map].
+///
+/// ```
+/// use serde_json::json;
+/// use treelike::{Treelike, adapter::JsonTree};
+///
+/// let doc = json!({
+/// 	"name": "treelike",
+/// 	"authors": [{ "name": "djugei" }],
+/// });
+///
+/// let names: Vec<_> = JsonTree::new(&doc)
+/// 	.iter_pointers(())
+/// 	.filter(|(_, value)| value.is_string())
+/// 	.map(|(pointer, _)| pointer)
+/// 	.collect();
+/// assert_eq!(names, ["/authors/0/name", "/name"]);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct JsonTree<'a> {
+	key: Key<'a>,
+	value: &'a Value,
+}
+
+impl<'a> JsonTree<'a> {
+	pub fn new(root: &'a Value) -> Self {
+		JsonTree {
+			key: Key::Root,
+			value: root,
+		}
+	}
+
+	/// Pre-order traversal yielding the JSON pointer of each value, see [Value::pointer].
+	pub fn iter_pointers<F: FilterBuilder<Self>>(self, filter: F) -> Pointers<Self, F> {
+		Pointers::new(self, filter)
+	}
+}
+
+impl<'a> PartialEq for JsonTree<'a> {
+	fn eq(&self, other: &Self) -> bool { core::ptr::eq(self.value, other.value) }
+}
+
+impl<'a> Treelike for JsonTree<'a> {
+	type Content = (Key<'a>, &'a Value);
+
+	fn content(self) -> Self::Content { (self.key, self.value) }
+
+	type ChildIterator = JsonChildren<'a>;
+
+	fn children(self) -> Self::ChildIterator {
+		match self.value {
+			Value::Array(a) => JsonChildren::Array(a.iter().enumerate()),
+			Value::Object(o) => JsonChildren::Object(o.iter()),
+			_ => JsonChildren::Leaf,
+		}
+	}
+}
+
+/// Iterator over the children of a [JsonTree].
+#[derive(Clone, Debug)]
+pub enum JsonChildren<'a> {
+	Leaf,
+	Array(Enumerate<slice::Iter<'a, Value>>),
+	Object(map::Iter<'a>),
+}
+
+impl<'a> Iterator for JsonChildren<'a> {
+	type Item = JsonTree<'a>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		match self {
+			JsonChildren::Leaf => None,
+			JsonChildren::Array(a) => a.next().map(|(i, value)| JsonTree {
+				key: Key::Index(i),
+				value,
+			}),
+			JsonChildren::Object(o) => o.next().map(|(name, value)| JsonTree {
+				key: Key::Name(name),
+				value,
+			}),
+		}
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		match self {
+			JsonChildren::Leaf => (0, Some(0)),
+			JsonChildren::Array(a) => a.size_hint(),
+			JsonChildren::Object(o) => o.size_hint(),
+		}
+	}
+}
+
+impl<'a> DoubleEndedIterator for JsonChildren<'a> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		match self {
+			JsonChildren::Leaf => None,
+			JsonChildren::Array(a) => a.next_back().map(|(i, value)| JsonTree {
+				key: Key::Index(i),
+				value,
+			}),
+			JsonChildren::Object(o) => o.next_back().map(|(name, value)| JsonTree {
+				key: Key::Name(name),
+				value,
+			}),
+		}
+	}
+}
+
+impl<'a> ExactSizeIterator for JsonChildren<'a> {}
+
+#[test]
+fn json_test() {
+	use serde_json::json;
+
+	let doc = json!({
+		"a/b": [1, { "c~": null }],
+		"d": { "e": [[true]] },
+	});
+	let root = JsonTree::new(&doc);
+
+	// every pointer resolves to the value it was produced with
+	let pointers: Vec<_> = root.iter_pointers(()).collect();
+	assert_eq!(pointers.len(), 9);
+	for (pointer, value) in &pointers {
+		assert!(core::ptr::eq(doc.pointer(pointer).unwrap(), *value));
+	}
+	let pointers: Vec<_> = pointers.into_iter().map(|(p, _)| p).collect();
+	assert_eq!(pointers, [
+		"",
+		"/a~1b",
+		"/a~1b/0",
+		"/a~1b/1",
+		"/a~1b/1/c~0",
+		"/d",
+		"/d/e",
+		"/d/e/0",
+		"/d/e/0/0"
+	]);
+
+	// searching works like a small query language
+	let (key, _) = root
+		.iter_dft_pre(())
+		.find(|(_, value)| value.is_null())
+		.unwrap();
+	assert_eq!(key, Key::Name("c~"));
+	let leaves = root
+		.iter_dft_rev(())
+		.filter(|(_, v)| !v.is_object() && !v.is_array());
+	let leaves: Vec<_> = leaves.map(|(key, _)| key).collect();
+	assert_eq!(leaves, [Key::Index(0), Key::Name("c~"), Key::Index(0)]);
+
+	// nodes are compared by identity, not by value
+	let doc = json!([[1], [1]]);
+	let root = JsonTree::new(&doc);
+	let target = root.children().nth(1).unwrap().children().next().unwrap();
+	assert_eq!(root.path_to(target).unwrap().as_ref(), &[1, 0]);
+}
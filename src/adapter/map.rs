@@ -0,0 +1,133 @@
+#![cfg(feature = "alloc")]
+
+use crate::Treelike;
+use alloc::collections::{BTreeMap, btree_map};
+
+/// A node that stores its children in a [BTreeMap] by name, like a directory.
+///
+/// The content is the name the node is stored under, [None] for the root, and the node itself.
+/// Children are visited ordered by their names.
+///
+/// ```
+/// use std::collections::BTreeMap;
+/// use treelike::{Treelike, adapter::MapTree};
+///
+/// #[derive(Default)]
+/// struct Dir(BTreeMap<&'static str, Dir>);
+///
+/// let mut root = Dir::default();
+/// let mut src = Dir::default();
+/// src.0.insert("main.rs", Dir::default());
+/// root.0.insert("src", src);
+/// root.0.insert("Cargo.toml", Dir::default());
+///
+/// let names: Vec<_> = MapTree::new(&root, |d: &Dir| &d.0)
+/// 	.iter_dft_pre(())
+/// 	.filter_map(|(name, _)| name.copied())
+/// 	.collect();
+/// assert_eq!(names, ["Cargo.toml", "src", "main.rs"]);
+/// ```
+#[derive(Debug)]
+pub struct MapTree<'a, K, N, F> {
+	key: Option<&'a K>,
+	node: &'a N,
+	children: F,
+}
+
+impl<'a, K, N, F: Fn(&'a N) -> &'a BTreeMap<K, N> + Copy> MapTree<'a, K, N, F> {
+	/// Creates a root that is not stored under any name.
+	pub fn new(node: &'a N, children: F) -> Self {
+		MapTree {
+			key: None,
+			node,
+			children,
+		}
+	}
+}
+
+impl<'a, K, N, F: Copy> Copy for MapTree<'a, K, N, F> {}
+
+impl<'a, K, N, F: Copy> Clone for MapTree<'a, K, N, F> {
+	fn clone(&self) -> Self { *self }
+}
+
+impl<'a, K, N, F: Fn(&'a N) -> &'a BTreeMap<K, N> + Copy> Treelike for MapTree<'a, K, N, F> {
+	type Content = (Option<&'a K>, &'a N);
+
+	fn content(self) -> Self::Content { (self.key, self.node) }
+
+	type ChildIterator = MapChildren<'a, K, N, F>;
+
+	fn children(self) -> Self::ChildIterator {
+		MapChildren {
+			iter: (self.children)(self.node).iter(),
+			children: self.children,
+		}
+	}
+}
+
+/// Iterator over the children of a [MapTree].
+#[derive(Debug)]
+pub struct MapChildren<'a, K, N, F> {
+	iter: btree_map::Iter<'a, K, N>,
+	children: F,
+}
+
+impl<'a, K, N, F: Copy> MapChildren<'a, K, N, F> {
+	fn wrap(&self, (key, node): (&'a K, &'a N)) -> MapTree<'a, K, N, F> {
+		MapTree {
+			key: Some(key),
+			node,
+			children: self.children,
+		}
+	}
+}
+
+impl<'a, K, N, F: Copy> Iterator for MapChildren<'a, K, N, F> {
+	type Item = MapTree<'a, K, N, F>;
+
+	fn next(&mut self) -> Option<Self::Item> { self.iter.next().map(|c| self.wrap(c)) }
+
+	fn size_hint(&self) -> (usize, Option<usize>) { self.iter.size_hint() }
+}
+
+impl<'a, K, N, F: Copy> DoubleEndedIterator for MapChildren<'a, K, N, F> {
+	fn next_back(&mut self) -> Option<Self::Item> { self.iter.next_back().map(|c| self.wrap(c)) }
+}
+
+impl<'a, K, N, F: Copy> ExactSizeIterator for MapChildren<'a, K, N, F> {}
+
+/// Two MapTrees are the same node if they point to the same node.
+impl<'a, K, N, F> PartialEq for MapTree<'a, K, N, F> {
+	fn eq(&self, other: &Self) -> bool { core::ptr::eq(self.node, other.node) }
+}
+
+#[test]
+fn map_test() {
+	struct Entry {
+		size: usize,
+		children: BTreeMap<u32, Entry>,
+	}
+	let root = Entry {
+		size: 0,
+		children: BTreeMap::from([
+			(2, Entry {
+				size: 5,
+				children: BTreeMap::new(),
+			}),
+			(1, Entry {
+				size: 0,
+				children: BTreeMap::from([(7, Entry {
+					size: 3,
+					children: BTreeMap::new(),
+				})]),
+			}),
+		]),
+	};
+
+	let tree = MapTree::new(&root, |e: &Entry| &e.children);
+	let total: usize = tree.iter_dft(()).map(|(_, e)| e.size).sum();
+	assert_eq!(total, 8);
+	let keys: Vec<_> = tree.iter_bft(()).map(|(k, _)| k.copied()).collect();
+	assert_eq!(keys, [None, Some(1), Some(2), Some(7)]);
+}
@@ -0,0 +1,55 @@
+//! Makes data that was not written with [Treelike][crate::Treelike] in mind traversable.
+//!
+//! Like [LinTree][crate::example::LinTree] does for slices, the adapters here wrap a reference to
+//! a node and a [Copy] function that finds its children, so no new type has to be defined:
+//!
+//! - [Nested] for nodes that keep their children in a slice or `Vec`
+//! - `MapTree` for nodes that keep named children in a `BTreeMap`, like directories
+//! - [Unary] for linked lists chained through an [Option]
+//!
+//! With the `std` feature `DirTree` reads directories from disk.
+//!
+//! Documents parsed by serde_json, toml and serde_yaml are trees too. Enable the feature with the
+//! name of the crate to get `JsonTree`, `TomlTree` or `YamlTree`. Their content is the `Key` a
+//! value is stored under and the value, `Pointers` turns that into JSON pointers.
+//!
+//! The `roxmltree` feature implements [Treelike][crate::Treelike] for XML nodes, see `xml`.
+
+mod nested;
+pub use nested::{Nested, NestedChildren};
+
+mod unary;
+pub use unary::Unary;
+
+#[cfg(feature = "alloc")]
+mod map;
+#[cfg(feature = "alloc")]
+pub use map::{MapChildren, MapTree};
+
+#[cfg(feature = "std")]
+mod dir;
+#[cfg(feature = "std")]
+pub use dir::{DirEntry, DirTree};
+
+#[cfg(feature = "alloc")]
+mod pointer;
+#[cfg(feature = "alloc")]
+pub use pointer::{Key, Pointers, Segment};
+
+#[cfg(feature = "serde_json")]
+mod json;
+#[cfg(feature = "serde_json")]
+pub use json::{JsonChildren, JsonTree};
+
+#[cfg(feature = "toml")]
+mod toml;
+#[cfg(feature = "toml")]
+pub use self::toml::{TomlChildren, TomlTree};
+
+#[cfg(feature = "serde_yaml")]
+mod yaml;
+#[cfg(feature = "serde_yaml")]
+pub use yaml::{YamlChildren, YamlTree};
+
+#[cfg(feature = "roxmltree")]
+pub mod xml;
@@ -0,0 +1,120 @@
+use crate::Treelike;
+use core::slice;
+
+/// A node that stores its children in a slice, for example a `(T, Vec<Node>)`-shaped struct.
+///
+/// The content is the node itself.
+///
+/// ```
+/// use treelike::{Treelike, adapter::Nested};
+///
+/// struct Node(&'static str, Vec<Node>);
+///
+/// let tree = Node("a", vec![
+/// 	Node("b", vec![Node("c", vec![])]),
+/// 	Node("d", vec![]),
+/// ]);
+///
+/// let mut names = Vec::new();
+/// Nested::new(&tree, |n: &Node| &n.1[..]).callback_dft_pre(|n, _| names.push(n.0), ());
+/// assert_eq!(names, ["a", "b", "c", "d"]);
+/// ```
+#[derive(Debug)]
+pub struct Nested<'a, N, F> {
+	node: &'a N,
+	children: F,
+}
+
+impl<'a, N, F: Fn(&'a N) -> &'a [N] + Copy> Nested<'a, N, F> {
+	pub fn new(node: &'a N, children: F) -> Self { Nested { node, children } }
+}
+
+impl<'a, N, F: Copy> Copy for Nested<'a, N, F> {}
+
+impl<'a, N, F: Copy> Clone for Nested<'a, N, F> {
+	fn clone(&self) -> Self { *self }
+}
+
+impl<'a, N, F: Fn(&'a N) -> &'a [N] + Copy> Treelike for Nested<'a, N, F> {
+	type Content = &'a N;
+
+	fn content(self) -> Self::Content { self.node }
+
+	type ChildIterator = NestedChildren<'a, N, F>;
+
+	fn children(self) -> Self::ChildIterator {
+		NestedChildren {
+			iter: (self.children)(self.node).iter(),
+			children: self.children,
+		}
+	}
+}
+
+/// Iterator over the children of a [Nested].
+#[derive(Debug)]
+pub struct NestedChildren<'a, N, F> {
+	iter: slice::Iter<'a, N>,
+	children: F,
+}
+
+impl<'a, N, F: Copy> Iterator for NestedChildren<'a, N, F> {
+	type Item = Nested<'a, N, F>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let children = self.children;
+		self.iter.next().map(|node| Nested { node, children })
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) { self.iter.size_hint() }
+}
+
+impl<'a, N, F: Copy> DoubleEndedIterator for NestedChildren<'a, N, F> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let children = self.children;
+		self.iter.next_back().map(|node| Nested { node, children })
+	}
+}
+
+impl<'a, N, F: Copy> ExactSizeIterator for NestedChildren<'a, N, F> {}
+
+/// Two Nested are the same node if they point to the same node.
+impl<'a, N, F> PartialEq for Nested<'a, N, F> {
+	fn eq(&self, other: &Self) -> bool { core::ptr::eq(self.node, other.node) }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn nested_test() {
+	struct Node<T> {
+		content: T,
+		children: Vec<Node<T>>,
+	}
+	let tree = Node {
+		content: 0,
+		children: vec![
+			Node {
+				content: 1,
+				children: vec![],
+			},
+			Node {
+				content: 2,
+				children: vec![
+					Node {
+						content: 3,
+						children: vec![],
+					},
+					Node {
+						content: 4,
+						children: vec![],
+					},
+				],
+			},
+		],
+	};
+
+	let root = Nested::new(&tree, |n: &Node<u8>| &n.children[..]);
+	let contents: Vec<_> = root.iter_dft_rev(()).map(|n| n.content).collect();
+	assert_eq!(contents, [4, 3, 2, 1, 0]);
+	assert_eq!(root.level(1).len(), 2);
+	assert!(core::ptr::eq(root.last(), &tree.children[1].children[1]));
+}
@@ -0,0 +1,128 @@
+#![cfg(feature = "alloc")]
+
+use crate::{Treelike, events::Events, treelike::FilterBuilder};
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+/// Where a node is stored in its parent, part of the content of document trees like
+/// `JsonTree`.
+///
+/// K is the type of the keys of maps, usually [str].
+pub enum Key<'a, K: ?Sized = str> {
+	/// The node is the root of the document.
+	Root,
+	/// The node is an element of a list.
+	Index(usize),
+	/// The node is a value in a map.
+	Name(&'a K),
+}
+
+impl<'a, K: ?Sized> Copy for Key<'a, K> {}
+
+impl<'a, K: ?Sized> Clone for Key<'a, K> {
+	fn clone(&self) -> Self { *self }
+}
+
+impl<'a, K: ?Sized + fmt::Debug> fmt::Debug for Key<'a, K> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Key::Root => f.write_str("Root"),
+			Key::Index(i) => f.debug_tuple("Index").field(i).finish(),
+			Key::Name(k) => f.debug_tuple("Name").field(k).finish(),
+		}
+	}
+}
+
+impl<'a, K: ?Sized + PartialEq> PartialEq for Key<'a, K> {
+	fn eq(&self, other: &Self) -> bool {
+		match (self, other) {
+			(Key::Root, Key::Root) => true,
+			(Key::Index(a), Key::Index(b)) => a == b,
+			(Key::Name(a), Key::Name(b)) => a == b,
+			_ => false,
+		}
+	}
+}
+
+/// Map keys that can be written as part of a JSON pointer.
+pub trait Segment {
+	/// Appends the key to pointer, escaped as described in RFC 6901.
+	fn write_segment(&self, pointer: &mut String);
+}
+
+impl Segment for str {
+	fn write_segment(&self, pointer: &mut String) {
+		for c in self.chars() {
+			match c {
+				'~' => pointer.push_str("~0"),
+				'/' => pointer.push_str("~1"),
+				c => pointer.push(c),
+			}
+		}
+	}
+}
+
+/// Pre-order traversal of a document tree that also yields the JSON pointer of each node.
+///
+/// Works for every tree whose content is a [Key] and a value, the root has the empty pointer.
+/// Created by the `iter_pointers` methods of the document trees or [Pointers::new].
+pub struct Pointers<T: Treelike, F: FilterBuilder<T>> {
+	events: Events<T, F>,
+	pointer: String,
+	/// the length of the pointer before each node that is currently entered
+	lengths: Vec<usize>,
+}
+
+impl<T: Treelike, F: FilterBuilder<T>> Pointers<T, F> {
+	pub fn new(root: T, filter: F) -> Self {
+		Pointers {
+			events: root.iter_events(filter),
+			pointer: String::new(),
+			lengths: Vec::new(),
+		}
+	}
+}
+
+impl<'a, K, V, T, F> Iterator for Pointers<T, F>
+where
+	K: Segment + ?Sized + 'a,
+	T: Treelike<Content = (Key<'a, K>, V)>,
+	F: FilterBuilder<T>,
+{
+	type Item = (String, V);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		use crate::events::Event::*;
+		loop {
+			match self.events.next()? {
+				Enter((key, value), _) => {
+					self.lengths.push(self.pointer.len());
+					match key {
+						Key::Root => {},
+						Key::Index(i) => {
+							use core::fmt::Write;
+							// writing to a String can't fail
+							let _ = write!(self.pointer, "/{}", i);
+						},
+						Key::Name(name) => {
+							self.pointer.push('/');
+							name.write_segment(&mut self.pointer);
+						},
+					}
+					return Some((self.pointer.clone(), value));
+				},
+				Exit(..) => {
+					let len = self.lengths.pop().unwrap_or(0);
+					self.pointer.truncate(len);
+				},
+			}
+		}
+	}
+}
+
+#[test]
+fn segment_escaping() {
+	let mut pointer = String::new();
+	"a/b~c".write_segment(&mut pointer);
+	assert_eq!(pointer, "a~1b~0c");
+}
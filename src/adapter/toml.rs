@@ -0,0 +1,213 @@
+#![cfg(feature = "toml")]
+
+use super::{Key, Pointers};
+use crate::{Treelike, treelike::FilterBuilder};
+use alloc::string::String;
+use core::{fmt, iter::Enumerate, slice};
+use toml::{
+	Value,
+	map::{self, Map},
+};
+
+/// A node of a [toml] document.
+///
+/// Works like [JsonTree][super::JsonTree]. Parse the document into a [Value] to get a root,
+/// table members are visited in the order of the [Map][toml::map::Map].
+///
+/// ```
+/// use treelike::{Treelike, adapter::TomlTree};
+///
+/// let doc: toml::Value = r#"
+/// 	[package]
+/// 	name = "treelike"
+/// 	[features]
+/// 	std = ["alloc"]
+/// "#
+/// .parse()
+/// .unwrap();
+///
+/// let pointers: Vec<_> = TomlTree::new(&doc)
+/// 	.iter_pointers(())
+/// 	.map(|(p, _)| p)
+/// 	.collect();
+/// assert_eq!(pointers, [
+/// 	"",
+/// 	"/features",
+/// 	"/features/std",
+/// 	"/features/std/0",
+/// 	"/package",
+/// 	"/package/name"
+/// ]);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct TomlTree<'a> {
+	key: Key<'a>,
+	value: &'a Value,
+}
+
+impl<'a> TomlTree<'a> {
+	pub fn new(root: &'a Value) -> Self {
+		TomlTree {
+			key: Key::Root,
+			value: root,
+		}
+	}
+
+	/// Pre-order traversal yielding the JSON pointer of each value.
+	pub fn iter_pointers<F: FilterBuilder<Self>>(self, filter: F) -> Pointers<Self, F> {
+		Pointers::new(self, filter)
+	}
+}
+
+impl<'a> PartialEq for TomlTree<'a> {
+	fn eq(&self, other: &Self) -> bool { core::ptr::eq(self.value, other.value) }
+}
+
+impl<'a> Treelike for TomlTree<'a> {
+	type Content = (Key<'a>, &'a Value);
+
+	fn content(self) -> Self::Content { (self.key, self.value) }
+
+	type ChildIterator = TomlChildren<'a>;
+
+	fn children(self) -> Self::ChildIterator {
+		match self.value {
+			Value::Array(a) => TomlChildren(Children::Array(a.iter().enumerate())),
+			Value::Table(t) => TomlChildren(Children::Table(Table::new(t))),
+			_ => TomlChildren(Children::Leaf),
+		}
+	}
+}
+
+/// Iterator over the children of a [TomlTree].
+///
+/// Cloning walks the table up to the current position again.
+#[derive(Clone, Debug)]
+pub struct TomlChildren<'a>(Children<'a>);
+
+#[derive(Clone, Debug)]
+enum Children<'a> {
+	Leaf,
+	Array(Enumerate<slice::Iter<'a, Value>>),
+	Table(Table<'a>),
+}
+
+/// A [map::Iter] that counts the members it yielded from each end, as it can't be cloned.
+struct Table<'a> {
+	table: &'a Map<String, Value>,
+	front: usize,
+	back: usize,
+	iter: map::Iter<'a>,
+}
+
+impl<'a> Table<'a> {
+	fn new(table: &'a Map<String, Value>) -> Self {
+		Table {
+			table,
+			front: 0,
+			back: 0,
+			iter: table.iter(),
+		}
+	}
+}
+
+impl<'a> Clone for Table<'a> {
+	fn clone(&self) -> Self {
+		let mut iter = self.table.iter();
+		if self.front > 0 {
+			iter.nth(self.front - 1);
+		}
+		if self.back > 0 {
+			iter.nth_back(self.back - 1);
+		}
+		Table { iter, ..*self }
+	}
+}
+
+impl<'a> fmt::Debug for Table<'a> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_map().entries(self.clone().iter).finish()
+	}
+}
+
+impl<'a> Iterator for TomlChildren<'a> {
+	type Item = TomlTree<'a>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		match &mut self.0 {
+			Children::Leaf => None,
+			Children::Array(a) => a.next().map(|(i, value)| TomlTree {
+				key: Key::Index(i),
+				value,
+			}),
+			Children::Table(t) => t.iter.next().map(|(name, value)| {
+				t.front += 1;
+				TomlTree {
+					key: Key::Name(name),
+					value,
+				}
+			}),
+		}
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		match &self.0 {
+			Children::Leaf => (0, Some(0)),
+			Children::Array(a) => a.size_hint(),
+			Children::Table(t) => t.iter.size_hint(),
+		}
+	}
+}
+
+impl<'a> DoubleEndedIterator for TomlChildren<'a> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		match &mut self.0 {
+			Children::Leaf => None,
+			Children::Array(a) => a.next_back().map(|(i, value)| TomlTree {
+				key: Key::Index(i),
+				value,
+			}),
+			Children::Table(t) => t.iter.next_back().map(|(name, value)| {
+				t.back += 1;
+				TomlTree {
+					key: Key::Name(name),
+					value,
+				}
+			}),
+		}
+	}
+}
+
+impl<'a> ExactSizeIterator for TomlChildren<'a> {}
+
+#[test]
+fn toml_test() {
+	let doc: Value = "a = [1, 2]\n[b.c]\nd = 'x'\n".parse().unwrap();
+	let root = TomlTree::new(&doc);
+
+	assert_eq!(root.iter_dft(()).count(), 7);
+	let (key, value) = root.last();
+	assert_eq!((key, value.as_str()), (Key::Name("d"), Some("x")));
+	let ints: i64 = root.iter_bft(()).filter_map(|(_, v)| v.as_integer()).sum();
+	assert_eq!(ints, 3);
+
+	// clones continue where the original is, from both ends
+	let wide: Value = "a = 1\nb = 2\nc = 3\nd = 4\n".parse().unwrap();
+	let mut children = TomlTree::new(&wide).children();
+	children.next();
+	children.next_back();
+	let values = |children: TomlChildren| -> Vec<_> {
+		children
+			.filter_map(|node| node.content().1.as_integer())
+			.collect()
+	};
+	assert_eq!(values(children.clone()), [2, 3]);
+	assert_eq!(values(children.clone()), values(children));
+	let mut table = TomlTree::new(&doc["b"]["c"]).children();
+	assert_eq!(
+		format!("{table:?}"),
+		r#"TomlChildren(Table({"d": String("x")}))"#
+	);
+	table.next();
+	assert_eq!(format!("{:?}", table.clone()), "TomlChildren(Table({}))");
+}
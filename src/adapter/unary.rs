@@ -0,0 +1,80 @@
+use crate::Treelike;
+use core::option;
+
+/// A linked list chained through an [Option], seen as a tree in which each node has at most one
+/// child.
+///
+/// The content is the node itself.
+///
+/// ```
+/// use treelike::{Treelike, adapter::Unary};
+///
+/// struct List {
+/// 	value: u8,
+/// 	next: Option<Box<List>>,
+/// }
+///
+/// let list = List {
+/// 	value: 1,
+/// 	next: Some(Box::new(List {
+/// 		value: 2,
+/// 		next: None,
+/// 	})),
+/// };
+///
+/// let root = Unary::new(&list, |l: &List| l.next.as_deref());
+/// assert_eq!(root.last().value, 2);
+/// let mut sum = 0;
+/// root.callback_dft_pre(|l, _| sum += l.value, ());
+/// assert_eq!(sum, 3);
+/// ```
+#[derive(Debug)]
+pub struct Unary<'a, N, F> {
+	node: &'a N,
+	next: F,
+}
+
+impl<'a, N, F: Fn(&'a N) -> Option<&'a N> + Copy> Unary<'a, N, F> {
+	pub fn new(node: &'a N, next: F) -> Self { Unary { node, next } }
+}
+
+impl<'a, N, F: Copy> Copy for Unary<'a, N, F> {}
+
+impl<'a, N, F: Copy> Clone for Unary<'a, N, F> {
+	fn clone(&self) -> Self { *self }
+}
+
+impl<'a, N, F: Fn(&'a N) -> Option<&'a N> + Copy> Treelike for Unary<'a, N, F> {
+	type Content = &'a N;
+
+	fn content(self) -> Self::Content { self.node }
+
+	type ChildIterator = option::IntoIter<Self>;
+
+	fn children(self) -> Self::ChildIterator {
+		let next = self.next;
+		next(self.node).map(|node| Unary { node, next }).into_iter()
+	}
+}
+
+/// Two Unary are the same node if they point to the same node.
+impl<'a, N, F> PartialEq for Unary<'a, N, F> {
+	fn eq(&self, other: &Self) -> bool { core::ptr::eq(self.node, other.node) }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn unary_test() {
+	struct Link<'a>(usize, Option<&'a Link<'a>>);
+
+	let c = Link(2, None);
+	let b = Link(1, Some(&c));
+	let a = Link(0, Some(&b));
+	let root = Unary::new(&a, |l: &Link| l.1);
+
+	// the depth of each node is its position in the list
+	root.callback_dft_pre(|l, depth| assert_eq!(l.0, depth), ());
+	assert!(core::ptr::eq(root.last(), &c));
+	assert_eq!(root.level(2).len(), 1);
+	assert!(root.level(3).is_empty());
+}
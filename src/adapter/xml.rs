@@ -0,0 +1,114 @@
+#![cfg(feature = "roxmltree")]
+
+//! [Treelike] and [TreelikeParent] for [roxmltree::Node].
+//!
+//! The content of a node is the node itself, it has the tag name, attributes and text.
+//! Documents contain more than elements, [Elements] and [NoWhitespace] filter the rest out.
+
+use crate::{Treelike, TreelikeParent, treelike::FilterBuilder};
+use core::iter::Filter;
+use roxmltree::{Children, Node};
+
+impl<'a, 'input: 'a> Treelike for Node<'a, 'input> {
+	type Content = Self;
+
+	fn content(self) -> Self::Content { self }
+
+	type ChildIterator = Children<'a, 'input>;
+
+	fn children(self) -> Self::ChildIterator { Node::children(&self) }
+}
+
+/// roxmltree keeps parents and siblings, so navigation is O(1).
+impl<'a, 'input: 'a> TreelikeParent for Node<'a, 'input> {
+	fn parent(self) -> Option<Self> { Node::parent(&self) }
+
+	fn root(self) -> Self { self.document().root() }
+
+	fn next_sibling(self) -> Option<Self> { Node::next_sibling(&self) }
+
+	fn prev_sibling(self) -> Option<Self> { Node::prev_sibling(&self) }
+}
+
+/// Only descends into elements, skipping text, comments and processing instructions.
+///
+/// ```
+/// use treelike::{Treelike, adapter::xml::Elements};
+///
+/// let xml = "<list><item>a</item><!-- b --><item>c</item></list>";
+/// let doc = roxmltree::Document::parse(xml).unwrap();
+///
+/// let tags: Vec<_> = doc
+/// 	.root_element()
+/// 	.iter_dft_pre(Elements)
+/// 	.map(|node| node.tag_name().name())
+/// 	.collect();
+/// assert_eq!(tags, ["list", "item", "item"]);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Elements;
+
+impl<'a, 'input: 'a> FilterBuilder<Node<'a, 'input>> for Elements {
+	type Filter = Filter<Children<'a, 'input>, fn(&Node<'a, 'input>) -> bool>;
+
+	fn build(self, _: Node<'a, 'input>, _: usize, children: Children<'a, 'input>) -> Self::Filter {
+		children.filter(|node| node.is_element())
+	}
+}
+
+/// Skips text nodes that only contain whitespace, like the indentation of pretty-printed
+/// documents.
+#[derive(Clone, Copy, Debug)]
+pub struct NoWhitespace;
+
+impl<'a, 'input: 'a> FilterBuilder<Node<'a, 'input>> for NoWhitespace {
+	type Filter = Filter<Children<'a, 'input>, fn(&Node<'a, 'input>) -> bool>;
+
+	fn build(self, _: Node<'a, 'input>, _: usize, children: Children<'a, 'input>) -> Self::Filter {
+		children.filter(|node| !node.is_text() || node.text().is_some_and(|t| !t.trim().is_empty()))
+	}
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn xml_test() {
+	use crate::treelike::MaxDepth;
+	use roxmltree::Document;
+
+	let xml = r#"
+		<crate name="treelike">
+			<module name="adapter">
+				<file name="xml.rs">roxmltree</file>
+				<?todo html?>
+			</module>
+			<module name="example"/>
+		</crate>
+	"#;
+	let doc = Document::parse(xml).unwrap();
+	let root = doc.root_element();
+
+	let names: Vec<_> = root
+		.iter_dft_pre(Elements)
+		.filter_map(|n| n.attribute("name"))
+		.collect();
+	assert_eq!(names, ["treelike", "adapter", "xml.rs", "example"]);
+
+	// the document itself is the root above the root element
+	assert_eq!(root.iter_dft(()).count(), 12);
+	assert_eq!(doc.root().iter_bft(NoWhitespace).count(), 7);
+	assert_eq!(root.iter_bft(MaxDepth::with(Elements, 1)).count(), 3);
+
+	let file = root
+		.iter_dft_pre(Elements)
+		.find(|n| n.has_tag_name("file"))
+		.unwrap();
+	assert_eq!(file.first_child().and_then(|t| t.text()), Some("roxmltree"));
+	assert_eq!(TreelikeParent::depth(file), 3);
+	assert!(TreelikeParent::root(file) == doc.root());
+	assert_eq!(
+		TreelikeParent::parent(file).unwrap().attribute("name"),
+		Some("adapter")
+	);
+	let last = root.iter_dft_pre_rev(NoWhitespace).nth(1).unwrap();
+	assert_eq!(last.attribute("name"), Some("example"));
+}
@@ -0,0 +1,194 @@
+#![cfg(feature = "serde_yaml")]
+
+use super::{Key, Pointers, Segment};
+use crate::{Treelike, treelike::FilterBuilder};
+use alloc::string::String;
+use core::{fmt, iter::Enumerate, slice};
+use serde_yaml::{Mapping, Value, mapping};
+
+/// A node of a [serde_yaml] document.
+///
+/// Works like [JsonTree][super::JsonTree], but the keys of mappings can be any [Value].
+/// Tagged values are transparent, a node with a tag has the same children as the value it tags.
+///
+/// ```
+/// use treelike::{Treelike, adapter::YamlTree};
+///
+/// let doc: serde_yaml::Value = serde_yaml::from_str(
+/// 	"
+/// steps:
+///   - run: cargo build
+///   - run: cargo test
+/// ",
+/// )
+/// .unwrap();
+///
+/// let runs: Vec<_> = YamlTree::new(&doc)
+/// 	.iter_pointers(())
+/// 	.filter(|(pointer, _)| pointer.ends_with("/run"))
+/// 	.map(|(pointer, value)| (pointer, value.as_str().unwrap()))
+/// 	.collect();
+/// assert_eq!(runs, [
+/// 	("/steps/0/run".to_string(), "cargo build"),
+/// 	("/steps/1/run".to_string(), "cargo test")
+/// ]);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct YamlTree<'a> {
+	key: Key<'a, Value>,
+	value: &'a Value,
+}
+
+impl<'a> YamlTree<'a> {
+	pub fn new(root: &'a Value) -> Self {
+		YamlTree {
+			key: Key::Root,
+			value: root,
+		}
+	}
+
+	/// Pre-order traversal yielding the JSON pointer of each value.
+	///
+	/// Keys that are not strings are written as YAML, without marking them. The mapping key `1`
+	/// and the string key `"1"` both become `/1`, just like the second element of a sequence, so
+	/// a pointer alone can't tell them apart. Use the [Key] in the content where that matters.
+	pub fn iter_pointers<F: FilterBuilder<Self>>(self, filter: F) -> Pointers<Self, F> {
+		Pointers::new(self, filter)
+	}
+}
+
+impl Segment for Value {
+	fn write_segment(&self, pointer: &mut String) {
+		match self {
+			Value::String(s) => s.write_segment(pointer),
+			other => {
+				let yaml = serde_yaml::to_string(other).unwrap_or_default();
+				yaml.trim_end().write_segment(pointer)
+			},
+		}
+	}
+}
+
+impl<'a> PartialEq for YamlTree<'a> {
+	fn eq(&self, other: &Self) -> bool { core::ptr::eq(self.value, other.value) }
+}
+
+impl<'a> Treelike for YamlTree<'a> {
+	type Content = (Key<'a, Value>, &'a Value);
+
+	fn content(self) -> Self::Content { (self.key, self.value) }
+
+	type ChildIterator = YamlChildren<'a>;
+
+	fn children(self) -> Self::ChildIterator {
+		let mut value = self.value;
+		while let Value::Tagged(tagged) = value {
+			value = &tagged.value;
+		}
+		match value {
+			Value::Sequence(s) => YamlChildren(Children::Sequence(s.iter().enumerate())),
+			Value::Mapping(m) => YamlChildren(Children::Mapping(Members::new(m))),
+			_ => YamlChildren(Children::Leaf),
+		}
+	}
+}
+
+/// Iterator over the children of a [YamlTree].
+///
+/// Unlike the other document trees this can not be reversed, as mappings can't. Cloning walks the
+/// mapping up to the current position again.
+#[derive(Clone, Debug)]
+pub struct YamlChildren<'a>(Children<'a>);
+
+#[derive(Clone, Debug)]
+enum Children<'a> {
+	Leaf,
+	Sequence(Enumerate<slice::Iter<'a, Value>>),
+	Mapping(Members<'a>),
+}
+
+/// A [mapping::Iter] that counts the members it yielded, as it can't be cloned.
+struct Members<'a> {
+	mapping: &'a Mapping,
+	taken: usize,
+	iter: mapping::Iter<'a>,
+}
+
+impl<'a> Members<'a> {
+	fn new(mapping: &'a Mapping) -> Self {
+		Members {
+			mapping,
+			taken: 0,
+			iter: mapping.iter(),
+		}
+	}
+}
+
+impl<'a> Clone for Members<'a> {
+	fn clone(&self) -> Self {
+		let mut iter = self.mapping.iter();
+		if self.taken > 0 {
+			iter.nth(self.taken - 1);
+		}
+		Members { iter, ..*self }
+	}
+}
+
+impl<'a> fmt::Debug for Members<'a> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_map().entries(self.clone().iter).finish()
+	}
+}
+
+impl<'a> Iterator for YamlChildren<'a> {
+	type Item = YamlTree<'a>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		match &mut self.0 {
+			Children::Leaf => None,
+			Children::Sequence(s) => s.next().map(|(i, value)| YamlTree {
+				key: Key::Index(i),
+				value,
+			}),
+			Children::Mapping(m) => m.iter.next().map(|(name, value)| {
+				m.taken += 1;
+				YamlTree {
+					key: Key::Name(name),
+					value,
+				}
+			}),
+		}
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		match &self.0 {
+			Children::Leaf => (0, Some(0)),
+			Children::Sequence(s) => s.size_hint(),
+			Children::Mapping(m) => m.iter.size_hint(),
+		}
+	}
+}
+
+impl<'a> ExactSizeIterator for YamlChildren<'a> {}
+
+#[test]
+fn yaml_test() {
+	let doc: Value = serde_yaml::from_str("1: !Tag [a, b]\ntrue: {x/y: z}\n").unwrap();
+	let root = YamlTree::new(&doc);
+
+	let pointers: Vec<_> = root.iter_pointers(()).map(|(p, _)| p).collect();
+	assert_eq!(pointers, ["", "/1", "/1/0", "/1/1", "/true", "/true/x~1y"]);
+	assert_eq!(root.level(2).len(), 3);
+	let (key, _) = root.last();
+	assert_eq!(key, Key::Name(&Value::from("x/y")));
+
+	// clones continue where the original is
+	let mut children = root.children();
+	children.next();
+	let clone = children.clone();
+	assert_eq!(
+		format!("{clone:?}"),
+		"YamlChildren(Mapping({Bool(true): Mapping {\"x/y\": String(\"z\")}}))"
+	);
+	assert!(clone.eq(children));
+}
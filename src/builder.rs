@@ -0,0 +1,37 @@
+//! Building owned trees from literals with the [tree!][crate::tree] macro.
+//!
+//! A node is written as its content, optionally followed by `=>` and a list of its children.
+//! Any tree implementing [FromParts] can be built this way.
+#![cfg_attr(feature = "alloc", doc = "```")]
+#![cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+//! use treelike::{Treelike, example::OwningTree, tree};
+//!
+//! let tree: OwningTree<u8> = tree![0 => [1, 2 => [3]]];
+//!
+//! let contents: Vec<_> = tree.iter_dft_pre(()).cloned().collect();
+//! assert_eq!(contents, [0, 1, 2, 3]);
+//! ```
+
+/// Trees that can be built from the content of the root and a list of subtrees.
+pub trait FromParts: Sized {
+	type Content;
+
+	fn from_parts<I: IntoIterator<Item = Self>>(content: Self::Content, children: I) -> Self;
+}
+
+/// Builds a tree from a literal, see the [builder][crate::builder] module.
+///
+/// The type of the tree can usually not be inferred, so it has to be annotated.
+#[macro_export]
+macro_rules! tree {
+	// all children are matched at once, so only the depth of the tree is limited by recursion
+	($content:expr => [$($child:expr $(=> [$($inner:tt)*])?),* $(,)?] $(,)?) => {
+		$crate::builder::FromParts::from_parts(
+			$content,
+			[$($crate::tree!($child $(=> [$($inner)*])?)),*],
+		)
+	};
+	($content:expr $(,)?) => {
+		$crate::builder::FromParts::from_parts($content, [])
+	};
+}
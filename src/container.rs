@@ -0,0 +1,148 @@
+//! Common ways of storing children, used by `#[derive(Treelike)]`.
+//!
+//! [ChildContainer] turns a reference to the field holding the children into an iterator over
+//! references to them, [ChildContainerMut] does the same for mutable references. Implement them
+//! for your own containers to make them usable with the derive macros.
+//!
+//! Implemented for `Vec<N>`, `[N; K]`, `Vec<Box<N>>`, `Vec<Option<Box<N>>>`,
+//! `[Option<Box<N>>; K]`, `[Option<&N>; K]`, `Option<Box<N>>` and `Option<&N>`.
+//! Only the variants without `&` support mutation.
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::iter::Map;
+use core::{iter::FlatMap, option, slice};
+
+/// A field holding children of type N.
+pub trait ChildContainer<'a, N: 'a> {
+	type Iter: Iterator<Item = &'a N>;
+	fn child_iter(&'a self) -> Self::Iter;
+}
+
+/// A field holding children of type N that can be borrowed mutably.
+pub trait ChildContainerMut<'a, N: 'a> {
+	type IterMut: Iterator<Item = &'a mut N>;
+	fn child_iter_mut(&'a mut self) -> Self::IterMut;
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, N: 'a> ChildContainer<'a, N> for Vec<N> {
+	type Iter = slice::Iter<'a, N>;
+
+	fn child_iter(&'a self) -> Self::Iter { self.iter() }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, N: 'a> ChildContainerMut<'a, N> for Vec<N> {
+	type IterMut = slice::IterMut<'a, N>;
+
+	fn child_iter_mut(&'a mut self) -> Self::IterMut { self.iter_mut() }
+}
+
+impl<'a, N: 'a, const K: usize> ChildContainer<'a, N> for [N; K] {
+	type Iter = slice::Iter<'a, N>;
+
+	fn child_iter(&'a self) -> Self::Iter { self.iter() }
+}
+
+impl<'a, N: 'a, const K: usize> ChildContainerMut<'a, N> for [N; K] {
+	type IterMut = slice::IterMut<'a, N>;
+
+	fn child_iter_mut(&'a mut self) -> Self::IterMut { self.iter_mut() }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, N: 'a> ChildContainer<'a, N> for Vec<Box<N>> {
+	type Iter = Map<slice::Iter<'a, Box<N>>, fn(&'a Box<N>) -> &'a N>;
+
+	fn child_iter(&'a self) -> Self::Iter { self.iter().map(|b| &**b) }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, N: 'a> ChildContainerMut<'a, N> for Vec<Box<N>> {
+	type IterMut = Map<slice::IterMut<'a, Box<N>>, fn(&'a mut Box<N>) -> &'a mut N>;
+
+	fn child_iter_mut(&'a mut self) -> Self::IterMut { self.iter_mut().map(|b| &mut **b) }
+}
+
+/// Iterator over the filled slots of a slice of optional boxes.
+#[cfg(feature = "alloc")]
+pub type OptionBoxIter<'a, N> = FlatMap<
+	slice::Iter<'a, Option<Box<N>>>,
+	Option<&'a N>,
+	fn(&'a Option<Box<N>>) -> Option<&'a N>,
+>;
+
+/// Mutable version of [OptionBoxIter].
+#[cfg(feature = "alloc")]
+pub type OptionBoxIterMut<'a, N> = FlatMap<
+	slice::IterMut<'a, Option<Box<N>>>,
+	Option<&'a mut N>,
+	fn(&'a mut Option<Box<N>>) -> Option<&'a mut N>,
+>;
+
+#[cfg(feature = "alloc")]
+impl<'a, N: 'a, const K: usize> ChildContainer<'a, N> for [Option<Box<N>>; K] {
+	type Iter = OptionBoxIter<'a, N>;
+
+	fn child_iter(&'a self) -> Self::Iter { self.iter().flat_map(Option::as_deref) }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, N: 'a, const K: usize> ChildContainerMut<'a, N> for [Option<Box<N>>; K] {
+	type IterMut = OptionBoxIterMut<'a, N>;
+
+	fn child_iter_mut(&'a mut self) -> Self::IterMut {
+		self.iter_mut().flat_map(Option::as_deref_mut)
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, N: 'a> ChildContainer<'a, N> for Vec<Option<Box<N>>> {
+	type Iter = OptionBoxIter<'a, N>;
+
+	fn child_iter(&'a self) -> Self::Iter { self.iter().flat_map(Option::as_deref) }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, N: 'a> ChildContainerMut<'a, N> for Vec<Option<Box<N>>> {
+	type IterMut = OptionBoxIterMut<'a, N>;
+
+	fn child_iter_mut(&'a mut self) -> Self::IterMut {
+		self.iter_mut().flat_map(Option::as_deref_mut)
+	}
+}
+
+impl<'a, 'b: 'a, N: 'a, const K: usize> ChildContainer<'a, N> for [Option<&'b N>; K] {
+	type Iter = FlatMap<
+		slice::Iter<'a, Option<&'b N>>,
+		Option<&'a N>,
+		fn(&'a Option<&'b N>) -> Option<&'a N>,
+	>;
+
+	fn child_iter(&'a self) -> Self::Iter { self.iter().flat_map(|child| *child) }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, N: 'a> ChildContainer<'a, N> for Option<Box<N>> {
+	type Iter = option::IntoIter<&'a N>;
+
+	fn child_iter(&'a self) -> Self::Iter { self.as_deref().into_iter() }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, N: 'a> ChildContainerMut<'a, N> for Option<Box<N>> {
+	type IterMut = option::IntoIter<&'a mut N>;
+
+	fn child_iter_mut(&'a mut self) -> Self::IterMut { self.as_deref_mut().into_iter() }
+}
+
+impl<'a, 'b: 'a, N: 'a> ChildContainer<'a, N> for Option<&'b N> {
+	type Iter = option::IntoIter<&'a N>;
+
+	fn child_iter(&'a self) -> Self::Iter {
+		let child: Option<&'a N> = *self;
+		child.into_iter()
+	}
+}
@@ -0,0 +1,167 @@
+//! Traversals that know where a node sits among its siblings.
+//!
+//! Knowing whether a node is the first or last child of its parent is needed for drawing trees
+//! with connectors, or for writing formats with separators between elements. See
+//! [callback_dft_ctx][Treelike::callback_dft_ctx] and
+//! [callback_dft_pre_ctx][Treelike::callback_dft_pre_ctx].
+//!
+//! Filters get the context of the node whose children they are filtering through
+//! [build_ctx][crate::treelike::FilterBuilder::build_ctx].
+
+use crate::{Treelike, treelike::FilterBuilder};
+use core::iter::Peekable;
+
+/// Position of a node in the tree, relative to its (visited) siblings.
+///
+/// The root is the first and last of one sibling at depth 0.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NodeContext {
+	pub depth: usize,
+	/// Index among the siblings that survived the filter.
+	pub index: usize,
+	/// Amount of visited siblings, including the node itself.
+	/// Only known if the filtered children report an exact [size_hint][Iterator::size_hint].
+	pub siblings: Option<usize>,
+	pub is_first: bool,
+	pub is_last: bool,
+}
+
+impl NodeContext {
+	/// Context of the node a traversal starts at.
+	pub fn root() -> Self {
+		NodeContext {
+			depth: 0,
+			index: 0,
+			siblings: Some(1),
+			is_first: true,
+			is_last: true,
+		}
+	}
+}
+
+/// Attaches a [NodeContext] to each child of a node.
+///
+/// Looks one child ahead to find out if the current one is the last.
+pub struct WithContext<I: Iterator> {
+	children: Peekable<I>,
+	depth: usize,
+	index: usize,
+	siblings: Option<usize>,
+}
+
+impl<I: Iterator> WithContext<I> {
+	/// Wraps the children of a node at depth `depth - 1`.
+	pub fn new(children: I, depth: usize) -> Self {
+		let siblings = match children.size_hint() {
+			(lower, Some(upper)) if lower == upper => Some(lower),
+			_ => None,
+		};
+		WithContext {
+			children: children.peekable(),
+			depth,
+			index: 0,
+			siblings,
+		}
+	}
+}
+
+impl<I: Iterator> Iterator for WithContext<I> {
+	type Item = (I::Item, NodeContext);
+	fn next(&mut self) -> Option<Self::Item> {
+		let child = self.children.next()?;
+		let context = NodeContext {
+			depth: self.depth,
+			index: self.index,
+			siblings: self.siblings,
+			is_first: self.index == 0,
+			is_last: self.children.peek().is_none(),
+		};
+		self.index += 1;
+		Some((child, context))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) { self.children.size_hint() }
+}
+
+pub(crate) fn callback_dft_ctx<
+	T: Treelike,
+	CB: FnMut(T::Content, NodeContext),
+	F: FilterBuilder<T>,
+>(
+	t: T,
+	mut cb: CB,
+	f: F,
+	context: NodeContext,
+	pre: bool,
+) -> CB {
+	if pre {
+		cb(t.clone().content(), context);
+	}
+
+	let filter = f.build_ctx(t.clone().content(), context, t.clone().children());
+	for (child, child_context) in WithContext::new(filter, context.depth + 1) {
+		cb = callback_dft_ctx(child, cb, f, child_context, pre);
+	}
+
+	if !pre {
+		cb(t.content(), context);
+	}
+	cb
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn context_test() {
+	use crate::{example::LinTree, treelike::Reversed};
+	use alloc::vec::Vec;
+
+	let base = [0, 1, 2, 3, 4, 5];
+	let root = LinTree::new(0, &base);
+
+	let mut pre = Vec::new();
+	root.callback_dft_pre_ctx(|c, ctx| pre.push((*c, ctx)), ());
+	let ctx = |depth, index, siblings, is_first, is_last| NodeContext {
+		depth,
+		index,
+		siblings,
+		is_first,
+		is_last,
+	};
+	assert_eq!(pre, [
+		(0, NodeContext::root()),
+		(1, ctx(1, 0, Some(2), true, false)),
+		(3, ctx(2, 0, Some(2), true, false)),
+		(4, ctx(2, 1, Some(2), false, true)),
+		(2, ctx(1, 1, Some(2), false, true)),
+		(5, ctx(2, 0, Some(1), true, true)),
+	]);
+
+	let mut post = Vec::new();
+	root.callback_dft_ctx(|c, ctx| post.push((*c, ctx)), ());
+	let mut expected = Vec::new();
+	root.callback_dft(|c, _| expected.push(*c), ());
+	assert_eq!(post.iter().map(|(c, _)| *c).collect::<Vec<_>>(), expected);
+	assert_eq!(post.last(), Some(&(0, NodeContext::root())));
+
+	// filtered out siblings do not count, the closure filter hides the exact count
+	let mut pre = Vec::new();
+	root.callback_dft_pre_ctx(
+		|c, ctx| pre.push((*c, ctx)),
+		root.child_filter(|_, _, child| *child.content() != 4),
+	);
+	assert_eq!(pre[2], (3, ctx(2, 0, None, true, true)));
+
+	// filters can prune by the context, here only first children are descended into
+	let first = root.child_filter_ctx(|_, ctx, _| ctx.is_first);
+	let mut pre = Vec::new();
+	root.callback_dft_pre_ctx(|c, _| pre.push(*c), first);
+	assert_eq!(pre, [0, 1, 3, 4, 2]);
+	let mut pre = Vec::new();
+	root.callback_dft_pre_ctx(|c, _| pre.push(*c), Reversed(first));
+	assert_eq!(pre, [0, 2, 5, 1]);
+
+	// without a context every node counts as an only child
+	let mut pre = Vec::new();
+	root.callback_dft_pre(|c, _| pre.push(*c), first);
+	assert_eq!(pre, [0, 1, 3, 4, 2, 5]);
+}
@@ -0,0 +1,223 @@
+//! Stateful navigation over any [Treelike].
+//!
+//! A [Cursor] points at one node of a tree and can be moved to a child, its parent or its
+//! siblings. The way down is recorded, so "up" works even for trees whose nodes don't know their
+//! parent.
+//!
+//! # no_std note
+//! Where the recorded path is stored is decided by the [PathStack] type parameter.
+//! [ArrayCursor] stores up to a fixed amount of levels inline, `VecCursor` grows as needed but
+//! requires allocations.
+
+use crate::Treelike;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Storage for the path a [Cursor] took from the root to its current node.
+///
+/// Each entry is an ancestor together with the index of the child that was descended into.
+pub trait PathStack<T> {
+	/// Pushes an entry, returning false if there is no space left.
+	fn push(&mut self, entry: (T, usize)) -> bool;
+	fn pop(&mut self) -> Option<(T, usize)>;
+	fn last(&self) -> Option<&(T, usize)>;
+	fn len(&self) -> usize;
+	fn is_empty(&self) -> bool { self.len() == 0 }
+}
+
+/// A [PathStack] that stores at most N entries inline, without allocating.
+pub struct ArrayStack<T, const N: usize> {
+	entries: [Option<(T, usize)>; N],
+	len: usize,
+}
+
+impl<T, const N: usize> Default for ArrayStack<T, N> {
+	fn default() -> Self {
+		ArrayStack {
+			entries: core::array::from_fn(|_| None),
+			len: 0,
+		}
+	}
+}
+
+impl<T, const N: usize> PathStack<T> for ArrayStack<T, N> {
+	fn push(&mut self, entry: (T, usize)) -> bool {
+		if let Some(slot) = self.entries.get_mut(self.len) {
+			*slot = Some(entry);
+			self.len += 1;
+			true
+		} else {
+			false
+		}
+	}
+
+	fn pop(&mut self) -> Option<(T, usize)> {
+		self.len = self.len.checked_sub(1)?;
+		self.entries[self.len].take()
+	}
+
+	fn last(&self) -> Option<&(T, usize)> { self.entries[..self.len].last()?.as_ref() }
+
+	fn len(&self) -> usize { self.len }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> PathStack<T> for Vec<(T, usize)> {
+	fn push(&mut self, entry: (T, usize)) -> bool {
+		Vec::push(self, entry);
+		true
+	}
+
+	fn pop(&mut self) -> Option<(T, usize)> { Vec::pop(self) }
+
+	fn last(&self) -> Option<&(T, usize)> { self.as_slice().last() }
+
+	fn len(&self) -> usize { Vec::len(self) }
+}
+
+/// A cursor, or zipper, pointing at one node of a tree.
+///
+/// All moves return the new current node, or [None] if the move was not possible, in which case
+/// the cursor stays where it was.
+///
+/// # Example
+/// ```
+/// # use treelike::example::LinTree;
+/// # use treelike::Treelike;
+/// use treelike::cursor::ArrayCursor;
+///
+/// let base = [0, 1, 2, 3, 4];
+/// let mut cursor: ArrayCursor<_, 8> = ArrayCursor::new(LinTree::new(0, &base));
+///
+/// cursor.down(0);
+/// cursor.down(1);
+/// assert_eq!(*cursor.content(), 4);
+/// assert!(cursor.next_sibling().is_none());
+/// cursor.prev_sibling();
+/// assert_eq!(*cursor.content(), 3);
+/// cursor.up();
+/// cursor.next_sibling();
+/// assert_eq!(*cursor.content(), 2);
+/// ```
+pub struct Cursor<T: Treelike, S: PathStack<T>> {
+	node: T,
+	stack: S,
+}
+
+/// A [Cursor] that can descend at most N levels below its root, without allocating.
+pub type ArrayCursor<T, const N: usize> = Cursor<T, ArrayStack<T, N>>;
+
+/// A [Cursor] without depth limit.
+#[cfg(feature = "alloc")]
+pub type VecCursor<T> = Cursor<T, Vec<(T, usize)>>;
+
+impl<T: Treelike, S: PathStack<T> + Default> Cursor<T, S> {
+	pub fn new(root: T) -> Self {
+		Cursor {
+			node: root,
+			stack: S::default(),
+		}
+	}
+}
+
+impl<T: Treelike, S: PathStack<T>> Cursor<T, S> {
+	/// The node the cursor currently points at.
+	pub fn node(&self) -> T { self.node.clone() }
+
+	pub fn content(&self) -> T::Content { self.node.clone().content() }
+
+	/// How many levels the cursor is below the root it was created with.
+	pub fn depth(&self) -> usize { self.stack.len() }
+
+	/// The index of the current node among its siblings, [None] at the root.
+	pub fn index(&self) -> Option<usize> { self.stack.last().map(|(_, index)| *index) }
+
+	/// Moves to the child at index.
+	///
+	/// Also fails if the [PathStack] is full.
+	pub fn down(&mut self, index: usize) -> Option<T> {
+		let child = self.node.clone().children().nth(index)?;
+		if self.stack.push((self.node.clone(), index)) {
+			self.node = child.clone();
+			Some(child)
+		} else {
+			None
+		}
+	}
+
+	/// Moves to the parent.
+	pub fn up(&mut self) -> Option<T> {
+		let (parent, _) = self.stack.pop()?;
+		self.node = parent.clone();
+		Some(parent)
+	}
+
+	/// Moves back to the root the cursor was created with.
+	pub fn to_root(&mut self) -> T {
+		while self.up().is_some() {}
+		self.node.clone()
+	}
+
+	/// Moves to the sibling directly right of the current node.
+	pub fn next_sibling(&mut self) -> Option<T> {
+		let index = self.index()?.checked_add(1)?;
+		self.move_to_sibling(index)
+	}
+
+	/// Moves to the sibling directly left of the current node.
+	pub fn prev_sibling(&mut self) -> Option<T> {
+		let index = self.index()?.checked_sub(1)?;
+		self.move_to_sibling(index)
+	}
+
+	fn move_to_sibling(&mut self, index: usize) -> Option<T> {
+		let parent = self.stack.last()?.0.clone();
+		let sibling = parent.clone().children().nth(index)?;
+		self.stack.pop();
+		self.stack.push((parent, index));
+		self.node = sibling.clone();
+		Some(sibling)
+	}
+}
+
+#[test]
+fn array_cursor_test() {
+	use crate::example::LinTree;
+	let base = [0, 1, 2, 3, 4, 5, 6];
+	let mut cursor: ArrayCursor<_, 1> = Cursor::new(LinTree::new(0, &base));
+
+	assert!(cursor.up().is_none());
+	assert!(cursor.next_sibling().is_none());
+	assert_eq!(cursor.index(), None);
+
+	assert_eq!(*cursor.down(1).unwrap().content(), 2);
+	assert_eq!(cursor.index(), Some(1));
+	// stack is full
+	assert!(cursor.down(0).is_none());
+	assert_eq!(*cursor.content(), 2);
+
+	assert_eq!(*cursor.prev_sibling().unwrap().content(), 1);
+	assert!(cursor.prev_sibling().is_none());
+	assert_eq!(cursor.depth(), 1);
+	assert_eq!(*cursor.to_root().content(), 0);
+	assert_eq!(cursor.depth(), 0);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn vec_cursor_test() {
+	use crate::example::LinTree;
+	let base = [0, 1, 2, 3, 4, 5, 6, 7, 8];
+	let mut cursor: VecCursor<_> = Cursor::new(LinTree::new(0, &base));
+
+	for _ in 0..3 {
+		cursor.down(0).unwrap();
+	}
+	assert_eq!(*cursor.content(), 7);
+	assert_eq!(*cursor.next_sibling().unwrap().content(), 8);
+	assert!(cursor.down(0).is_none());
+	assert_eq!(*cursor.up().unwrap().content(), 3);
+	assert_eq!(*cursor.next_sibling().unwrap().content(), 4);
+	assert!(cursor.next_sibling().is_none());
+	assert_eq!(cursor.depth(), 2);
+}
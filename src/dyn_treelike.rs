@@ -0,0 +1,131 @@
+//! An object-safe counterpart to [Treelike], for trees that are only known at runtime.
+//!
+//! [Treelike] can not be made into a trait object because of its `Sized` bound, its by-value
+//! methods and the [ChildIterator][Treelike::ChildIterator] type. [DynTreelike] takes nodes by
+//! reference and enumerates children through a callback instead, so
+//! `&dyn DynTreelike<Content = C>` can be passed around freely and nodes of one tree may have
+//! different types.
+//!
+//! Every [Treelike] is also a [DynTreelike].
+//!
+//! ```
+//! use treelike::DynTreelike;
+//!
+//! struct File(&'static str);
+//! struct Dir(
+//! 	&'static str,
+//! 	Vec<Box<dyn DynTreelike<Content = &'static str>>>,
+//! );
+//!
+//! impl DynTreelike for File {
+//! 	type Content = &'static str;
+//! 	fn dyn_content(&self) -> Self::Content { self.0 }
+//! 	fn for_each_child(&self, _: &mut dyn FnMut(&dyn DynTreelike<Content = Self::Content>)) {}
+//! }
+//!
+//! impl DynTreelike for Dir {
+//! 	type Content = &'static str;
+//! 	fn dyn_content(&self) -> Self::Content { self.0 }
+//! 	fn for_each_child(&self, f: &mut dyn FnMut(&dyn DynTreelike<Content = Self::Content>)) {
+//! 		self.1.iter().for_each(|child| f(&**child))
+//! 	}
+//! }
+//!
+//! let tree = Dir("src", vec![
+//! 	Box::new(Dir("bin", vec![Box::new(File("main.rs"))])),
+//! 	Box::new(File("lib.rs")),
+//! ]);
+//!
+//! let mut names = Vec::new();
+//! tree.dyn_dft_pre(&mut |name, depth| names.push((name, depth)));
+//! assert_eq!(names, [
+//! 	("src", 0),
+//! 	("bin", 1),
+//! 	("main.rs", 2),
+//! 	("lib.rs", 1)
+//! ]);
+//! ```
+
+use crate::Treelike;
+
+/// Object-safe version of [Treelike], see the [module documentation][self].
+pub trait DynTreelike {
+	/// The content of the current node, see [Treelike::Content].
+	type Content;
+
+	/// Has to produce this nodes [Content][DynTreelike::Content].
+	fn dyn_content(&self) -> Self::Content;
+
+	/// Has to call f once for each direct child of this node, in order.
+	fn for_each_child(&self, f: &mut dyn FnMut(&dyn DynTreelike<Content = Self::Content>));
+
+	/// Traverses the tree depth first, post order, like [Treelike::callback_dft].
+	///
+	/// # no_std note
+	/// This uses the call-stack, just like [Treelike::callback_dft].
+	fn dyn_dft(&self, callback: &mut dyn FnMut(Self::Content, usize)) {
+		dyn_dft(self, callback, 0, false)
+	}
+
+	/// Traverses the tree depth first, pre order, like [Treelike::callback_dft_pre].
+	fn dyn_dft_pre(&self, callback: &mut dyn FnMut(Self::Content, usize)) {
+		dyn_dft(self, callback, 0, true)
+	}
+}
+
+impl<T: Treelike> DynTreelike for T {
+	type Content = T::Content;
+
+	fn dyn_content(&self) -> Self::Content { self.clone().content() }
+
+	fn for_each_child(&self, f: &mut dyn FnMut(&dyn DynTreelike<Content = Self::Content>)) {
+		for child in self.clone().children() {
+			f(&child);
+		}
+	}
+}
+
+fn dyn_dft<T: DynTreelike + ?Sized>(
+	t: &T,
+	cb: &mut dyn FnMut(T::Content, usize),
+	depth: usize,
+	pre: bool,
+) {
+	if pre {
+		cb(t.dyn_content(), depth);
+	}
+	t.for_each_child(&mut |child| dyn_dft(child, cb, depth + 1, pre));
+	if !pre {
+		cb(t.dyn_content(), depth);
+	}
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn dyn_bridge_test() {
+	use crate::example::{LinTree, OwningTree};
+	use alloc::{boxed::Box, vec::Vec};
+
+	let base = [0, 1, 2, 3, 4, 5];
+	let lin = LinTree::new(0, &base);
+	let owning = OwningTree::new(7, alloc::vec![OwningTree::new(8, Vec::new())]);
+	let trees: Vec<Box<dyn DynTreelike<Content = &usize>>> =
+		alloc::vec![Box::new(lin), Box::new(&owning)];
+
+	let mut post = Vec::new();
+	let mut pre = Vec::new();
+	for tree in &trees {
+		tree.dyn_dft(&mut |c, d| post.push((*c, d)));
+		tree.dyn_dft_pre(&mut |c, d| pre.push((*c, d)));
+	}
+
+	let mut expected = Vec::new();
+	lin.callback_dft(|c, d| expected.push((*c, d)), ());
+	expected.extend([(8, 1), (7, 0)]);
+	assert_eq!(post, expected);
+
+	let mut expected = Vec::new();
+	lin.callback_dft_pre(|c, d| expected.push((*c, d)), ());
+	expected.extend([(7, 0), (8, 1)]);
+	assert_eq!(pre, expected);
+}
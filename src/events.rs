@@ -0,0 +1,148 @@
+//! Traversing a tree as a flat stream of [Event]s, like a SAX parser would report a document.
+//!
+//! Every node produces an [Enter][Event::Enter] before and an [Exit][Event::Exit] after the events
+//! of its children. This is both the pre- and post-order traversal in one, making it easy to
+//! write nested formats or keep track of scopes.
+//!
+//! See [callback_events][Treelike::callback_events] and `iter_events`,
+//! `OwningTree::from_events` turns a stream back into a tree.
+
+use crate::{Treelike, treelike::FilterBuilder};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// One side of a node visit, carrying the nodes content and depth.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Event<C> {
+	/// The node is entered, its children follow.
+	Enter(C, usize),
+	/// All children have been visited, the node is left.
+	Exit(C, usize),
+}
+
+impl<C> Event<C> {
+	pub fn content(&self) -> &C {
+		match self {
+			Event::Enter(c, _) | Event::Exit(c, _) => c,
+		}
+	}
+
+	pub fn depth(&self) -> usize {
+		match self {
+			Event::Enter(_, d) | Event::Exit(_, d) => *d,
+		}
+	}
+
+	/// Converts the content, for example to turn references into owned values.
+	pub fn map<D>(self, f: impl FnOnce(C) -> D) -> Event<D> {
+		match self {
+			Event::Enter(c, d) => Event::Enter(f(c), d),
+			Event::Exit(c, d) => Event::Exit(f(c), d),
+		}
+	}
+}
+
+pub(crate) fn callback_events<T: Treelike, CB: FnMut(Event<T::Content>), F: FilterBuilder<T>>(
+	t: T,
+	mut cb: CB,
+	f: F,
+	depth: usize,
+) -> CB {
+	cb(Event::Enter(t.clone().content(), depth));
+
+	let filter = f.build(t.clone().content(), depth, t.clone().children());
+	for child in filter {
+		cb = callback_events(child, cb, f, depth + 1)
+	}
+
+	cb(Event::Exit(t.content(), depth));
+	cb
+}
+
+/// Iterator over the [Event]s of a tree, see [iter_events][Treelike::iter_events].
+#[cfg(feature = "alloc")]
+pub struct Events<T: Treelike, F: FilterBuilder<T>> {
+	stack: Vec<(T, F::Filter)>,
+	filter: F,
+	next: Option<T>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Treelike, F: FilterBuilder<T>> Events<T, F> {
+	pub(crate) fn new(treelike: T, filter: F) -> Self {
+		Self {
+			stack: Vec::new(),
+			filter,
+			next: Some(treelike),
+		}
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Treelike, F: FilterBuilder<T>> Iterator for Events<T, F> {
+	type Item = Event<T::Content>;
+	fn next(&mut self) -> Option<Self::Item> {
+		if let Some(node) = self.next.take() {
+			let depth = self.stack.len();
+			let filtered =
+				self.filter
+					.build(node.clone().content(), depth, node.clone().children());
+			self.stack.push((node.clone(), filtered));
+			return Some(Event::Enter(node.content(), depth));
+		}
+
+		let (_, children) = self.stack.last_mut()?;
+		if let Some(child) = children.next() {
+			self.next = Some(child);
+			self.next()
+		} else {
+			let (node, _) = self.stack.pop()?;
+			Some(Event::Exit(node.content(), self.stack.len()))
+		}
+	}
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn events_test() {
+	use crate::example::LinTree;
+	let base = [0, 1, 2, 3, 4, 5];
+	let root = LinTree::new(0, &base);
+
+	let mut callback = Vec::new();
+	root.callback_events(|e| callback.push(e.map(|c| *c)), ());
+	let iter: Vec<_> = root.iter_events(()).map(|e| e.map(|c| *c)).collect();
+	assert_eq!(callback, iter);
+
+	use Event::*;
+	assert_eq!(iter, [
+		Enter(0, 0),
+		Enter(1, 1),
+		Enter(3, 2),
+		Exit(3, 2),
+		Enter(4, 2),
+		Exit(4, 2),
+		Exit(1, 1),
+		Enter(2, 1),
+		Enter(5, 2),
+		Exit(5, 2),
+		Exit(2, 1),
+		Exit(0, 0),
+	]);
+
+	let enters: Vec<_> = iter
+		.iter()
+		.filter(|e| matches!(e, Enter(..)))
+		.map(|e| *e.content())
+		.collect();
+	let pre: Vec<_> = root.iter_dft_pre(()).cloned().collect();
+	assert_eq!(enters, pre);
+
+	let exits: Vec<_> = iter
+		.iter()
+		.filter(|e| matches!(e, Exit(..)))
+		.map(|e| *e.content())
+		.collect();
+	let post: Vec<_> = root.iter_dft(()).cloned().collect();
+	assert_eq!(exits, post);
+}
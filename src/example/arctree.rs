@@ -0,0 +1,116 @@
+#![cfg(feature = "alloc")]
+
+use crate::{Treelike, builder::FromParts};
+use alloc::{sync::Arc, vec::Vec};
+use core::ops::Deref;
+
+/// A node of an [ArcTree].
+#[derive(Debug)]
+pub struct ArcNode<Content> {
+	pub content: Content,
+	pub children: Vec<ArcTree<Content>>,
+}
+
+/// An immutable tree of atomically reference counted nodes.
+///
+/// Subtrees can be shared between several trees and threads. Like [RcTree][super::RcTree]
+/// [Treelike] is implemented on the [Clone]-able handle and visiting a node clones its content.
+#[derive(Debug)]
+pub struct ArcTree<Content>(Arc<ArcNode<Content>>);
+
+impl<Content> ArcTree<Content> {
+	pub fn new(content: Content, children: Vec<Self>) -> Self {
+		ArcTree(Arc::new(ArcNode { content, children }))
+	}
+}
+
+impl<Content> FromParts for ArcTree<Content> {
+	type Content = Content;
+
+	fn from_parts<I: IntoIterator<Item = Self>>(content: Content, children: I) -> Self {
+		ArcTree::new(content, children.into_iter().collect())
+	}
+}
+
+impl<Content> Clone for ArcTree<Content> {
+	fn clone(&self) -> Self { ArcTree(self.0.clone()) }
+}
+
+impl<Content> Deref for ArcTree<Content> {
+	type Target = ArcNode<Content>;
+
+	fn deref(&self) -> &Self::Target { &self.0 }
+}
+
+/// Nodes are equal if they are the same node.
+impl<Content> PartialEq for ArcTree<Content> {
+	fn eq(&self, other: &Self) -> bool { Arc::ptr_eq(&self.0, &other.0) }
+}
+
+impl<Content: Clone> Treelike for ArcTree<Content> {
+	type Content = Content;
+
+	fn content(self) -> Self::Content { self.0.content.clone() }
+
+	type ChildIterator = ArcChildren<Content>;
+
+	fn children(self) -> Self::ChildIterator {
+		let back = self.0.children.len();
+		ArcChildren {
+			node: self,
+			front: 0,
+			back,
+		}
+	}
+}
+
+/// Iterator over the children of an [ArcTree].
+pub struct ArcChildren<Content> {
+	node: ArcTree<Content>,
+	front: usize,
+	back: usize,
+}
+
+impl<Content> Iterator for ArcChildren<Content> {
+	type Item = ArcTree<Content>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.front == self.back {
+			return None;
+		}
+		self.front += 1;
+		Some(self.node.0.children[self.front - 1].clone())
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.back - self.front;
+		(len, Some(len))
+	}
+}
+
+impl<Content> DoubleEndedIterator for ArcChildren<Content> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.front == self.back {
+			return None;
+		}
+		self.back -= 1;
+		Some(self.node.0.children[self.back].clone())
+	}
+}
+
+impl<Content> ExactSizeIterator for ArcChildren<Content> {}
+
+#[test]
+fn arc_tree_test() {
+	let shared: ArcTree<_> = crate::tree![2 => [3]];
+	let a = ArcTree::new(0, alloc::vec![shared.clone(), ArcTree::new(1, Vec::new())]);
+	let b = ArcTree::new(4, alloc::vec![shared.clone()]);
+
+	let handle = std::thread::spawn(move || a.iter_dft_pre(()).collect::<Vec<_>>());
+	let b: Vec<_> = b.iter_dft_rev(()).collect();
+	assert_eq!(handle.join().unwrap(), [0, 2, 3, 1]);
+	assert_eq!(b, [3, 2, 4]);
+
+	let lca = crate::lca::LcaIndex::new(shared.clone());
+	assert!(lca.node(1) == Some(shared.children[0].clone()));
+}
@@ -29,6 +29,7 @@ impl<'a, TreeCont> Treelike for &'a BorrowingBinaryTree<'a, TreeCont> {
 }
 
 #[test]
+#[allow(clippy::field_reassign_with_default)]
 fn borrowing_tree_works() {
 	extern crate alloc;
 	use alloc::vec::Vec;
@@ -58,10 +59,13 @@ fn borrowing_tree_works() {
 	let mut limited = Vec::new();
 	a.callback_dft(
 		|val, _depth| limited.push(*val),
-		(|_content, depth, _tree| depth == 0)
-			as for<'r, 's> fn(&'r &usize, usize, &'s &BorrowingBinaryTree<'_, usize>) -> _,
+		a.child_filter(|_content, depth, _tree| depth == 0),
 	);
 	assert_eq!(alloc::vec![1, 2, 0], limited);
+
+	let mut mirrored = Vec::new();
+	a.callback_dft_rev(|val, _depth| mirrored.push(*val), ());
+	assert_eq!(alloc::vec![3, 2, 1, 0], mirrored);
 }
 
 #[test]
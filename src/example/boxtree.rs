@@ -1,6 +1,6 @@
 #![cfg(feature = "alloc")]
 
-use crate::Treelike;
+use crate::{Treelike, builder::FromParts};
 use alloc::boxed::Box;
 
 /// A basic binary tree that stores its children in [Box]-es on the heap.
@@ -19,6 +19,25 @@ impl<Content: Default> Default for OwningBinaryTree<Content> {
 	}
 }
 
+/// Panics if given more than two children. Children fill the left slot first, so a node with
+/// only a right child can not be built this way.
+impl<Content> FromParts for OwningBinaryTree<Content> {
+	type Content = Content;
+
+	fn from_parts<I: IntoIterator<Item = Self>>(content: Content, children: I) -> Self {
+		let mut children = children.into_iter().map(Box::new);
+		let tree = OwningBinaryTree {
+			content,
+			children: [children.next(), children.next()],
+		};
+		assert!(
+			children.next().is_none(),
+			"a binary tree has at most two children"
+		);
+		tree
+	}
+}
+
 use core::borrow::Borrow;
 fn reborrow<A, R: Borrow<A>>(r: &R) -> &A { r.borrow() }
 
@@ -43,6 +62,18 @@ fn default_works() {
 	a.last();
 }
 
+#[test]
+fn tree_macro() {
+	let tree: OwningBinaryTree<usize> = crate::tree![0 => [1 => [2], 3 => [4, 5]]];
+	let contents: Vec<_> = tree.iter_bft(()).cloned().collect();
+	assert_eq!(contents, [0, 1, 3, 2, 4, 5]);
+	assert!(tree.children[1].as_ref().unwrap().children[1].is_some());
+}
+
+#[test]
+#[should_panic]
+fn tree_macro_too_many_children() { let _: OwningBinaryTree<usize> = crate::tree![0 => [1, 2, 3]]; }
+
 #[test]
 fn option_box_size() {
 	assert_eq!(
@@ -1,4 +1,4 @@
-use crate::Treelike;
+use crate::{Treelike, TreelikeParent};
 
 /// A tree whose nodes are stored in a backing slice.
 ///
@@ -21,64 +21,155 @@ pub struct LinTree<'a, T> {
 impl<'a, T> LinTree<'a, T> {
 	pub fn new(index: usize, slice: &'a [T]) -> Self { LinTree { index, slice } }
 
+	/// Returns all values at depth below this node.
+	///
+	/// The nodes of one depth-layer of a subtree are stored next to each other, so this is just
+	/// a subslice.
+	///
+	/// ```
+	/// # use treelike::example::LinTree;
+	/// let base = [0, 1, 2, 3, 4, 5, 6, 7];
+	///
+	/// assert_eq!(LinTree::new(0, &base).level_slice(2), &[3, 4, 5, 6]);
+	/// assert_eq!(LinTree::new(1, &base).level_slice(2), &[7]);
+	/// assert!(LinTree::new(2, &base).level_slice(2).is_empty());
+	/// ```
+	pub fn level_slice(&self, depth: usize) -> &'a [T] {
+		// the subtree of index i starts at (i + 1) * 2^depth - 1 on each level
+		let width = u32::try_from(depth)
+			.ok()
+			.and_then(|depth| 1usize.checked_shl(depth));
+		let start = width.and_then(|w| (self.index + 1).checked_mul(w));
+		match (width, start) {
+			(Some(width), Some(start)) => {
+				let len = self.slice.len();
+				let start = (start - 1).min(len);
+				&self.slice[start..(start + width).min(len)]
+			},
+			_ => &[],
+		}
+	}
+
 	fn tuple_new((index, slice): (usize, &'a [T])) -> Option<Self> {
 		slice.get(index).map(|_| Self::new(index, slice))
 	}
 }
 
+/// Depth of the node stored at index in a LinTree rooted at 0.
+fn depth_of(index: usize) -> usize {
+	let usize_bits = (core::mem::size_of::<usize>() * 8) as u32;
+	// this is flooring log2 for integers
+	// find the first one by subtracting the bit-length from the leading_zeros
+	// first one - 1 is already floored log2
+	(usize_bits - (index + 1).leading_zeros() - 1) as usize
+}
+
 impl<'a, T> Copy for LinTree<'a, T> {}
 
 impl<'a, T> Clone for LinTree<'a, T> {
-	fn clone(&self) -> Self {
-		Self {
-			index: self.index,
-			slice: self.slice,
-		}
-	}
+	fn clone(&self) -> Self { *self }
 }
 
 impl<'a, T: core::fmt::Debug> Treelike for LinTree<'a, T> {
 	type Content = &'a T;
 
-	type ChildIterator = core::iter::FlatMap<
-		core::iter::Zip<
-			core::iter::Chain<core::iter::Once<usize>, core::iter::Once<usize>>,
-			core::iter::Repeat<&'a [T]>,
-		>,
-		Option<LinTree<'a, T>>,
-		fn((usize, &'a [T])) -> Option<LinTree<'a, T>>,
-	>;
+	type ChildIterator = LinChildren<'a, T>;
 
 	fn content(self) -> Self::Content { &self.slice[self.index] }
 
 	fn children(self) -> Self::ChildIterator {
-		use core::iter::{once, repeat};
 		let left = 2 * self.index + 1;
 		let right = 2 * self.index + 2;
-		once(left)
-			.chain(once(right))
-			.zip(repeat(self.slice))
-			.flat_map(Self::tuple_new)
+		let end = self.slice.len().clamp(left, right + 1);
+		LinChildren {
+			indices: left..end,
+			slice: self.slice,
+		}
 	}
 
 	/// This is also an example of overriding the [Treelike]s default implementations where
 	/// necessary. LinTree can provide breadth-first traversal with a simple iteration
 	fn callback_bft<CB: FnMut(Self::Content, usize)>(self, mut callback: CB) {
-		let usize_bits = (core::mem::size_of::<usize>() * 8) as u32;
 		for (i, content) in self.slice.iter().enumerate().skip(self.index) {
-			// this is flooring log2 for integers
-			// find the first one by subtracting the bit-length from the leading_zeros
-			// first one - 1 is already floored log2
-			let depth = usize_bits - (i + 1).leading_zeros() - 1;
-			callback(content, depth as usize);
+			callback(content, depth_of(i));
 		}
 	}
 
+	fn callback_level<CB: FnMut(Self::Content)>(self, depth: usize, callback: CB) {
+		self.level_slice(depth).iter().for_each(callback)
+	}
+
 	//TODO could also implement filtered bft, but that requires itertools group_by. put that in
 	//as an optional dependency maybe
 }
 
-#[cfg(feature = "test")]
+/// Iterator over the children of a [LinTree].
+///
+/// As the children are just a range of indices this can be iterated from both ends.
+#[derive(Debug)]
+pub struct LinChildren<'a, T> {
+	indices: core::ops::Range<usize>,
+	slice: &'a [T],
+}
+
+impl<'a, T> Iterator for LinChildren<'a, T> {
+	type Item = LinTree<'a, T>;
+	fn next(&mut self) -> Option<Self::Item> {
+		let slice = self.slice;
+		self.indices.next().map(|i| LinTree::new(i, slice))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) { self.indices.size_hint() }
+}
+
+impl<'a, T> DoubleEndedIterator for LinChildren<'a, T> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let slice = self.slice;
+		self.indices.next_back().map(|i| LinTree::new(i, slice))
+	}
+}
+
+impl<'a, T> ExactSizeIterator for LinChildren<'a, T> {}
+
+/// Two LinTrees are the same node if they point to the same index of the same slice.
+impl<'a, T> PartialEq for LinTree<'a, T> {
+	fn eq(&self, other: &Self) -> bool {
+		self.index == other.index && core::ptr::eq(self.slice, other.slice)
+	}
+}
+
+/// The parent of a LinTree node can be calculated from its index, so all of these are O(1)
+/// overrides of the default implementations.
+impl<'a, T: core::fmt::Debug> TreelikeParent for LinTree<'a, T> {
+	fn parent(self) -> Option<Self> {
+		self.index
+			.checked_sub(1)
+			.map(|i| Self::new(i / 2, self.slice))
+	}
+
+	fn root(self) -> Self { Self::new(0, self.slice) }
+
+	fn depth(self) -> usize { depth_of(self.index) }
+
+	fn next_sibling(self) -> Option<Self> {
+		// only left children, which have odd indices, have a right sibling
+		if self.index % 2 == 1 {
+			Self::tuple_new((self.index + 1, self.slice))
+		} else {
+			None
+		}
+	}
+
+	fn prev_sibling(self) -> Option<Self> {
+		// right children have even indices, the root has no siblings
+		if self.index == 0 || self.index % 2 == 1 {
+			None
+		} else {
+			Some(Self::new(self.index - 1, self.slice))
+		}
+	}
+}
+
 #[test]
 fn depth_test() {
 	extern crate alloc;
@@ -96,6 +187,59 @@ fn depth_test() {
 	assert_eq!(&state, &[2, 2, 2, 3, 3, 3, 3, 3, 3, 3, 3, 4]);
 }
 
+#[test]
+fn parent_test() {
+	extern crate alloc;
+	use alloc::vec::Vec;
+	let base = [0, (1), 2, (3), 4, 5, 6, (7), 8, 9, 10, 11, 12, 13, 14, (15)];
+
+	let node = LinTree::new(9, &base);
+	let ancestors: Vec<_> = node.ancestors().map(|n| *n.content()).collect();
+	assert_eq!(ancestors, [4, 1, 0]);
+	assert_eq!(node.depth(), 3);
+	assert_eq!(*node.root().content(), 0);
+
+	assert_eq!(node.prev_sibling(), None);
+	assert_eq!(node.next_sibling(), Some(LinTree::new(10, &base)));
+	assert_eq!(LinTree::new(10, &base).prev_sibling(), Some(node));
+	assert_eq!(LinTree::new(15, &base).next_sibling(), None);
+	let siblings: Vec<_> = node.siblings().map(|n| *n.content()).collect();
+	assert_eq!(siblings, [10]);
+
+	let root = LinTree::new(0, &base);
+	assert_eq!(root.parent(), None);
+	assert_eq!(root.siblings().count(), 0);
+}
+
+/// Checks the LinTree overrides against the provided implementations.
+#[test]
+fn parent_default_test() {
+	let base = [0, (1), 2, (3), 4, 5, 6, (7), 8, 9, 10, 11, 12, 13, 14, (15)];
+	for i in 0..base.len() {
+		let node = LinTree::new(i, &base);
+		let root = node.ancestors().last().unwrap_or(node);
+		assert_eq!(node.root(), root);
+		assert_eq!(node.depth(), node.ancestors().count());
+
+		let parent_children = node.parent().map(Treelike::children);
+		let mut prev = None;
+		let mut next = None;
+		if let Some(mut children) = parent_children {
+			let mut last = None;
+			for child in &mut children {
+				if child == node {
+					prev = last;
+					next = children.next();
+					break;
+				}
+				last = Some(child);
+			}
+		}
+		assert_eq!(node.prev_sibling(), prev);
+		assert_eq!(node.next_sibling(), next);
+	}
+}
+
 #[test]
 fn basic_test() {
 	extern crate alloc;
@@ -149,3 +293,319 @@ fn bft_pre_test() {
 	let iter_state: Vec<_> = root.iter_bft(()).cloned().collect();
 	assert_eq!(iter_state, state);
 }
+
+#[cfg(feature = "alloc")]
+#[test]
+fn lca_of_test() {
+	let base = [0, (1), 2, (3), 4, 5, 6, (7), 8, 9, 10, 11, 12, 13, 14, (15)];
+	let root = LinTree::new(0, &base);
+
+	let a = LinTree::new(15, &base);
+	let b = LinTree::new(9, &base);
+	assert_eq!(root.path_to(a).unwrap().as_ref(), &[0, 0, 0, 0]);
+	assert_eq!(root.lca_of(a, b), Some(LinTree::new(1, &base)));
+	assert_eq!(root.lca_of(a, root), Some(root));
+
+	let sub = LinTree::new(2, &base);
+	assert_eq!(sub.path_to(a), None);
+	assert_eq!(sub.lca_of(a, b), None);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn dft_pre_skip_test() {
+	use alloc::vec::Vec;
+	let base = [0, (1), 2, (3), 4, 5, 6, (7), 8, 9, 10, 11, 12, 13, 14, (15)];
+	let root = LinTree::new(0, &base);
+
+	let mut depths = Vec::new();
+	root.callback_dft_pre(|_, depth| depths.push(depth), ());
+	let mut iter = root.iter_dft_pre(());
+	assert_eq!(iter.depth(), None);
+	let mut iter_depths = Vec::new();
+	while iter.next().is_some() {
+		iter_depths.push(iter.depth().unwrap());
+	}
+	assert_eq!(depths, iter_depths);
+
+	let mut iter = root.iter_dft_pre(());
+	iter.skip_subtree();
+	let mut visited = Vec::new();
+	while let Some(content) = iter.next() {
+		visited.push(*content);
+		if *content == 3 || *content == 2 {
+			iter.skip_subtree();
+			iter.skip_subtree();
+		}
+	}
+	assert_eq!(visited, [0, 1, 3, 4, 9, 10, 2]);
+
+	// pruning going forward only does not hold on to the skipped children
+	let base: Vec<usize> = (0..1023).collect();
+	let root = LinTree::new(0, &base);
+	let alive = alloc::rc::Rc::new(());
+	let tracked = root.replace_children(|_, _, children| {
+		children
+			.zip(core::iter::repeat(alive.clone()))
+			.map(|(child, _)| child)
+	});
+	let mut iter = root.iter_dft_pre(tracked);
+	let mut visited = 0;
+	while iter.next().is_some() {
+		visited += 1;
+		if iter.depth() == Some(5) {
+			iter.skip_subtree();
+		}
+		assert!(alloc::rc::Rc::strong_count(&alive) <= 7);
+	}
+	assert_eq!(visited, 63);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn depth_limit_test() {
+	use crate::treelike::MaxDepth;
+	use alloc::vec::Vec;
+	let base = [0, (1), 2, (3), 4, 5, 6, (7), 8, 9, 10, 11, 12, 13, 14, (15)];
+	let root = LinTree::new(0, &base);
+
+	let bft: Vec<_> = root.iter_bft(()).cloned().collect();
+	let iddfs: Vec<_> = root.iter_iddfs(()).cloned().collect();
+	assert_eq!(bft, iddfs);
+
+	let mut iter = root.iter_iddfs(());
+	let mut depths = Vec::new();
+	while iter.next().is_some() {
+		depths.push(iter.depth());
+	}
+	let mut bft_depths = Vec::new();
+	root.callback_bft(|_, depth| bft_depths.push(depth));
+	assert_eq!(depths, bft_depths);
+
+	for max in 0..5 {
+		let limit = MaxDepth::new(max);
+		let bft: Vec<_> = root.iter_bft(limit).cloned().collect();
+		let iddfs: Vec<_> = root.iter_iddfs(limit).cloned().collect();
+		let mut callback = Vec::new();
+		root.callback_bft_filtered(|c, _| callback.push(*c), limit);
+		assert_eq!(bft, iddfs);
+		assert_eq!(bft, callback);
+		assert_eq!(bft.len(), base.len().min((1 << (max + 1)) - 1));
+
+		let dft: Vec<_> = root.iter_dft(limit).collect();
+		let mut callback = Vec::new();
+		root.callback_dft(|c, depth| callback.push((c, depth)), limit);
+		assert_eq!(dft.len(), bft.len());
+		assert!(callback.iter().all(|(_, depth)| *depth <= max));
+	}
+
+	// combined with another filter, only the left children
+	let left = MaxDepth::with(
+		root.child_filter(|_content, _depth, child| child.index % 2 == 1),
+		2,
+	);
+	let mut callback = Vec::new();
+	root.callback_bft_filtered(|c, _| callback.push(*c), left);
+	assert_eq!(callback, [0, 1, 3]);
+	let iddfs: Vec<_> = root.iter_iddfs(left).cloned().collect();
+	assert_eq!(iddfs, [0, 1, 3]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn reverse_test() {
+	use crate::treelike::MaxDepth;
+	use alloc::vec::Vec;
+	let base = [0, (1), 2, (3), 4, 5, 6, (7), 8, 9, 10, 11, 12, 13, 14, (15)];
+	let root = LinTree::new(0, &base);
+
+	let mut pre = Vec::new();
+	root.callback_dft_pre(|c, _| pre.push(*c), ());
+	let mut post_rev = Vec::new();
+	root.callback_dft_rev(|c, _| post_rev.push(*c), ());
+	post_rev.reverse();
+	assert_eq!(pre, post_rev);
+
+	let mut post = Vec::new();
+	root.callback_dft(|c, _| post.push(*c), ());
+	let mut pre_rev = Vec::new();
+	root.callback_dft_pre_rev(|c, _| pre_rev.push(*c), ());
+	pre_rev.reverse();
+	assert_eq!(post, pre_rev);
+
+	let mut bft_rev = Vec::new();
+	root.callback_bft_rev(|c, _| bft_rev.push(*c));
+	assert_eq!(bft_rev, [
+		0, 2, 1, 6, 5, 4, 3, 14, 13, 12, 11, 10, 9, 8, 7, 15
+	]);
+	let iter_bft_rev: Vec<_> = root.iter_bft_rev(()).cloned().collect();
+	assert_eq!(bft_rev, iter_bft_rev);
+
+	let iter: Vec<_> = root.iter_dft_rev(()).cloned().collect();
+	let mut callback = Vec::new();
+	root.callback_dft_rev(|c, _| callback.push(*c), ());
+	assert_eq!(iter, callback);
+	let iter: Vec<_> = root.iter_dft_pre_rev(()).cloned().collect();
+	let mut callback = Vec::new();
+	root.callback_dft_pre_rev(|c, _| callback.push(*c), ());
+	assert_eq!(iter, callback);
+
+	// double ended iteration
+	let back: Vec<_> = root.iter_dft(()).rev().cloned().collect();
+	assert_eq!(back, iter);
+	let back: Vec<_> = root.iter_dft_pre(()).rev().cloned().collect();
+	let mut expected = pre.clone();
+	expected.reverse();
+	assert_eq!(back, expected);
+
+	// meeting in the middle, at every possible point
+	for front in 0..=pre.len() {
+		let mut iter = root.iter_dft_pre(());
+		let mut got: Vec<_> = iter.by_ref().take(front).cloned().collect();
+		let mut back: Vec<_> = iter.rev().cloned().collect();
+		back.reverse();
+		got.extend(back);
+		assert_eq!(got, pre);
+
+		let mut iter = root.iter_dft(());
+		let mut got: Vec<_> = iter.by_ref().take(front).cloned().collect();
+		let mut back: Vec<_> = iter.rev().cloned().collect();
+		back.reverse();
+		got.extend(back);
+		assert_eq!(got, post);
+	}
+
+	// alternating
+	let mut iter = root.iter_dft_pre(MaxDepth::new(2));
+	let mut front = Vec::new();
+	let mut back = Vec::new();
+	while let Some(c) = iter.next() {
+		front.push(*c);
+		match iter.next_back() {
+			Some(c) => back.push(*c),
+			None => break,
+		}
+	}
+	back.reverse();
+	front.extend(back);
+	assert_eq!(front, [0, 1, 3, 4, 2, 5, 6]);
+
+	// skipping while iterating from both ends
+	let mut iter = root.iter_dft_pre(());
+	assert_eq!(iter.next_back(), Some(&14));
+	assert_eq!(iter.next_back(), Some(&13));
+	assert_eq!(iter.next(), Some(&0));
+	assert_eq!(iter.next(), Some(&1));
+	iter.skip_subtree();
+	let rest: Vec<_> = iter.cloned().collect();
+	assert_eq!(rest, [2, 5, 11, 12, 6]);
+
+	let mut iter = root.iter_dft_pre(());
+	assert_eq!(iter.next_back(), Some(&14));
+	assert_eq!(iter.next(), Some(&0));
+	assert_eq!(iter.next(), Some(&1));
+	assert_eq!(iter.next(), Some(&3));
+	iter.skip_subtree();
+	assert_eq!(iter.next_back(), Some(&13));
+	let rest: Vec<_> = iter.cloned().collect();
+	assert_eq!(rest, [4, 9, 10, 2, 5, 11, 12, 6]);
+
+	// skipping before the back is first used
+	let mut iter = root.iter_dft_pre(());
+	assert_eq!(iter.next(), Some(&0));
+	assert_eq!(iter.next(), Some(&1));
+	iter.skip_subtree();
+	let rest: Vec<_> = iter.rev().cloned().collect();
+	assert_eq!(rest, [14, 13, 6, 12, 11, 5, 2]);
+
+	let mut iter = root.iter_dft_pre(());
+	assert_eq!(iter.next(), Some(&0));
+	assert_eq!(iter.next(), Some(&1));
+	assert_eq!(iter.next(), Some(&3));
+	iter.skip_subtree();
+	assert_eq!(iter.next(), Some(&4));
+	iter.skip_subtree();
+	assert_eq!(iter.next_back(), Some(&14));
+	let rest: Vec<_> = iter.cloned().collect();
+	assert_eq!(rest, [2, 5, 11, 12, 6, 13]);
+
+	// closure filters can be reversed as well
+	let odd = root.child_filter(|_content, _depth, child| child.index % 2 == 1 || child.index < 3);
+	let fwd: Vec<_> = root.iter_dft_pre(odd).cloned().collect();
+	let mut bwd: Vec<_> = root.iter_dft_rev(odd).cloned().collect();
+	bwd.reverse();
+	assert_eq!(fwd, [0, 1, 3, 7, 15, 2, 5, 11]);
+	assert_eq!(fwd, bwd);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn levels_test() {
+	use crate::treelike::{MaxDepth, callback_bft};
+	use alloc::vec::Vec;
+	let base = [0, (1), 2, (3), 4, 5, 6, (7), 8, 9, 10, 11, 12, 13, 14, (15)];
+
+	for index in 0..base.len() {
+		let node = LinTree::new(index, &base);
+		let levels: Vec<_> = node.iter_levels(()).collect();
+		let flat: Vec<_> = levels.iter().flatten().cloned().collect();
+		let bft: Vec<_> = node.iter_bft(()).collect();
+		assert_eq!(flat, bft);
+
+		for depth in 0..7 {
+			let expected = levels.get(depth).cloned().unwrap_or_default();
+			assert_eq!(node.level(depth), expected);
+
+			// the default implementation
+			let mut default = Vec::new();
+			let _ = callback_bft(node, |c| default.push(c), (), depth, 0);
+			assert_eq!(default, expected);
+		}
+	}
+
+	let root = LinTree::new(0, &base);
+	assert!(root.level_slice(usize::MAX).is_empty());
+	assert!(root.level_slice(63).is_empty());
+	assert!(LinTree::new(3, &base).level_slice(62).is_empty());
+	let limited: Vec<_> = root.iter_levels(MaxDepth::new(1)).collect();
+	assert_eq!(limited, [alloc::vec![&0], alloc::vec![&1, &2]]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn filter_closure_test() {
+	use crate::treelike::MaxDepth;
+	use alloc::vec::Vec;
+	let base = [0, 1, 2, 3, 4, 5, 6, 7, 8];
+	let root = LinTree::new(0, &base);
+
+	let even = root.child_filter(|_content, _depth, child| *child.content() % 2 == 0);
+	let visited: Vec<_> = root.iter_dft_pre(even).cloned().collect();
+	assert_eq!(visited, [0, 2, 6]);
+
+	// plain closures are filters too, also for the callbacks
+	let mut visited = Vec::new();
+	let even = |_: &&usize, _, child: &LinTree<usize>| child.content() % 2 == 0;
+	root.callback_dft_pre(|content, _depth| visited.push(*content), even);
+	assert_eq!(visited, [0, 2, 6]);
+
+	// the content can end up in the returned iterator
+	let below_parent = root.replace_children(|content, _depth, children| {
+		children.filter(move |child| *child.content() < content + 3)
+	});
+	let visited: Vec<_> = root.iter_dft_pre(below_parent).cloned().collect();
+	assert_eq!(visited, [0, 1, 3, 2]);
+	let limited: Vec<_> = root
+		.iter_bft(MaxDepth::with(below_parent, 1))
+		.cloned()
+		.collect();
+	assert_eq!(limited, [0, 1, 2]);
+
+	// fn pointers still work on their own
+	fn odd(_: &&usize, _: usize, child: &LinTree<'_, usize>) -> bool { *child.content() % 2 == 1 }
+	let visited: Vec<_> = root
+		.iter_dft_pre(odd as fn(&&usize, usize, &LinTree<'_, usize>) -> bool)
+		.cloned()
+		.collect();
+	assert_eq!(visited, [0, 1, 3, 7]);
+}
@@ -1,13 +1,31 @@
 //! This module contains some basic example trees that are used both for tests and for you to get
 //! some inspiration.
+//!
+//! The owning trees can be built with the [tree!][crate::tree] macro.
 
 mod borrowtree;
 mod lintree;
 
 pub use borrowtree::BorrowingBinaryTree;
-pub use lintree::LinTree;
+pub use lintree::{LinChildren, LinTree};
 
 #[cfg(feature = "alloc")]
 mod boxtree;
 #[cfg(feature = "alloc")]
 pub use boxtree::OwningBinaryTree;
+
+#[cfg(feature = "alloc")]
+mod vectree;
+#[cfg(feature = "alloc")]
+pub use vectree::OwningTree;
+
+#[cfg(feature = "alloc")]
+mod rctree;
+#[cfg(feature = "alloc")]
+pub use rctree::{RcChildren, RcNode, RcTree};
+
+// alloc::sync is missing on targets without atomic pointers
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+mod arctree;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+pub use arctree::{ArcChildren, ArcNode, ArcTree};
@@ -0,0 +1,154 @@
+#![cfg(feature = "alloc")]
+
+use crate::{Treelike, TreelikeParent};
+use alloc::{
+	rc::{Rc, Weak},
+	vec::Vec,
+};
+use core::{cell::RefCell, ops::Deref};
+
+/// A node of an [RcTree].
+#[derive(Debug)]
+pub struct RcNode<Content> {
+	pub content: Content,
+	parent: Weak<RcNode<Content>>,
+	children: RefCell<Vec<RcTree<Content>>>,
+}
+
+/// A tree of reference counted nodes that can grow after creation, like a DOM.
+///
+/// Each node knows its parent through a [Weak] pointer, so it implements [TreelikeParent].
+/// [Treelike] is implemented on the handle itself, which is [Clone] but not [Copy].
+/// Visiting a node clones its content, wrap it in an [Rc] if that is expensive.
+#[derive(Debug)]
+pub struct RcTree<Content>(Rc<RcNode<Content>>);
+
+impl<Content> RcTree<Content> {
+	/// Creates a new root.
+	pub fn new(content: Content) -> Self {
+		RcTree(Rc::new(RcNode {
+			content,
+			parent: Weak::new(),
+			children: RefCell::new(Vec::new()),
+		}))
+	}
+
+	/// Appends a new child to this node and returns it.
+	pub fn push(&self, content: Content) -> Self {
+		let child = RcTree(Rc::new(RcNode {
+			content,
+			parent: Rc::downgrade(&self.0),
+			children: RefCell::new(Vec::new()),
+		}));
+		self.0.children.borrow_mut().push(child.clone());
+		child
+	}
+}
+
+impl<Content> Clone for RcTree<Content> {
+	fn clone(&self) -> Self { RcTree(self.0.clone()) }
+}
+
+impl<Content> Deref for RcTree<Content> {
+	type Target = RcNode<Content>;
+
+	fn deref(&self) -> &Self::Target { &self.0 }
+}
+
+/// Nodes are equal if they are the same node.
+impl<Content> PartialEq for RcTree<Content> {
+	fn eq(&self, other: &Self) -> bool { Rc::ptr_eq(&self.0, &other.0) }
+}
+
+impl<Content: Clone> Treelike for RcTree<Content> {
+	type Content = Content;
+
+	fn content(self) -> Self::Content { self.0.content.clone() }
+
+	type ChildIterator = RcChildren<Content>;
+
+	fn children(self) -> Self::ChildIterator {
+		let back = self.0.children.borrow().len();
+		RcChildren {
+			node: self,
+			front: 0,
+			back,
+		}
+	}
+}
+
+impl<Content: Clone> TreelikeParent for RcTree<Content> {
+	fn parent(self) -> Option<Self> { self.0.parent.upgrade().map(RcTree) }
+}
+
+/// Iterator over the children of an [RcTree].
+///
+/// Children pushed while iterating are not visited.
+pub struct RcChildren<Content> {
+	node: RcTree<Content>,
+	front: usize,
+	back: usize,
+}
+
+impl<Content> RcChildren<Content> {
+	fn get(&self, index: usize) -> Option<RcTree<Content>> {
+		self.node.0.children.borrow().get(index).cloned()
+	}
+}
+
+impl<Content> Iterator for RcChildren<Content> {
+	type Item = RcTree<Content>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.front == self.back {
+			return None;
+		}
+		self.front += 1;
+		self.get(self.front - 1)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.back - self.front;
+		(len, Some(len))
+	}
+}
+
+impl<Content> DoubleEndedIterator for RcChildren<Content> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.front == self.back {
+			return None;
+		}
+		self.back -= 1;
+		self.get(self.back)
+	}
+}
+
+impl<Content> ExactSizeIterator for RcChildren<Content> {}
+
+#[test]
+fn rc_tree_test() {
+	let root = RcTree::new(0);
+	let one = root.push(1);
+	let two = root.push(2);
+	let three = one.push(3);
+	one.push(4);
+
+	let dft: Vec<_> = root.clone().iter_dft(()).collect();
+	assert_eq!(dft, [3, 4, 1, 2, 0]);
+	let bft: Vec<_> = root.clone().iter_bft_rev(()).collect();
+	assert_eq!(bft, [0, 2, 1, 4, 3]);
+	let mut pre = Vec::new();
+	root.clone().callback_dft_pre(|c, _| pre.push(c), ());
+	assert_eq!(pre, [0, 1, 3, 4, 2]);
+
+	assert!(three.clone().parent() == Some(one.clone()));
+	assert!(three.clone().root() == root);
+	assert_eq!(three.clone().depth(), 2);
+	assert!(one.clone().next_sibling() == Some(two.clone()));
+	assert!(two.clone().common_ancestor(three.clone()) == Some(root.clone()));
+	assert_eq!(three.content, 3);
+
+	// dropping the root leaves the children without parent
+	drop((root, one, two));
+	assert!(three.parent().is_none());
+}
@@ -0,0 +1,144 @@
+#![cfg(feature = "alloc")]
+
+use crate::{Treelike, builder::FromParts, events::Event};
+use alloc::vec::Vec;
+
+/// A tree with any amount of children per node, stored in a [Vec].
+///
+/// Like [OwningBinaryTree][crate::example::OwningBinaryTree] it owns all of its data.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OwningTree<Content> {
+	pub content: Content,
+	pub children: Vec<OwningTree<Content>>,
+}
+
+impl<Content> OwningTree<Content> {
+	pub fn new(content: Content, children: Vec<Self>) -> Self { OwningTree { content, children } }
+
+	/// Builds a tree from a stream of [Event]s as produced by
+	/// [iter_events][Treelike::iter_events].
+	///
+	/// Only the order of the events matters, the depths and the contents of
+	/// [Exit][Event::Exit] events are ignored. This makes it possible to build a tree from
+	/// several streams glued together.
+	///
+	/// Returns [None] if the stream does not describe exactly one tree.
+	///
+	/// ```
+	/// # use treelike::example::{LinTree, OwningTree};
+	/// # use treelike::Treelike;
+	/// let base = [1, 2, 3];
+	/// let events = LinTree::new(0, &base)
+	/// 	.iter_events(())
+	/// 	.map(|e| e.map(|c| *c));
+	/// let tree = OwningTree::from_events(events).unwrap();
+	///
+	/// assert_eq!(
+	/// 	tree,
+	/// 	OwningTree::new(1, vec![
+	/// 		OwningTree::new(2, vec![]),
+	/// 		OwningTree::new(3, vec![])
+	/// 	])
+	/// );
+	/// ```
+	pub fn from_events<I: IntoIterator<Item = Event<Content>>>(events: I) -> Option<Self> {
+		let mut stack: Vec<Self> = Vec::new();
+		let mut events = events.into_iter();
+		while let Some(event) = events.next() {
+			match event {
+				Event::Enter(content, _) => stack.push(OwningTree::new(content, Vec::new())),
+				Event::Exit(..) => {
+					let node = stack.pop()?;
+					match stack.last_mut() {
+						Some(parent) => parent.children.push(node),
+						// the root is done, nothing may follow it
+						None => return events.next().map_or(Some(node), |_| None),
+					}
+				},
+			}
+		}
+		None
+	}
+}
+
+impl<Content> FromParts for OwningTree<Content> {
+	type Content = Content;
+
+	fn from_parts<I: IntoIterator<Item = Self>>(content: Content, children: I) -> Self {
+		OwningTree::new(content, children.into_iter().collect())
+	}
+}
+
+impl<'a, TreeCont> Treelike for &'a OwningTree<TreeCont> {
+	type Content = &'a TreeCont;
+
+	fn content(self) -> Self::Content { &self.content }
+
+	type ChildIterator = core::slice::Iter<'a, OwningTree<TreeCont>>;
+
+	fn children(self) -> Self::ChildIterator { self.children.iter() }
+}
+
+#[test]
+fn event_roundtrip() {
+	use crate::example::LinTree;
+	let base: Vec<usize> = (0..12).collect();
+	let lin = LinTree::new(0, &base);
+
+	let tree = OwningTree::from_events(lin.iter_events(()).map(|e| e.map(|c| *c))).unwrap();
+	let lin_order: Vec<_> = lin.iter_dft(()).collect();
+	let tree_order: Vec<_> = tree.iter_dft(()).collect();
+	assert_eq!(lin_order, tree_order);
+
+	let again = OwningTree::from_events(tree.iter_events(()).map(|e| e.map(|c| *c))).unwrap();
+	assert_eq!(tree, again);
+}
+
+#[test]
+fn malformed_events() {
+	use Event::*;
+	assert_eq!(OwningTree::<u8>::from_events(None), None);
+	assert_eq!(OwningTree::from_events([Enter(1, 0)]), None);
+	assert_eq!(OwningTree::from_events([Exit(1, 0)]), None);
+	assert_eq!(
+		OwningTree::from_events([Enter(1, 0), Exit(1, 0), Enter(2, 0), Exit(2, 0)]),
+		None
+	);
+	assert_eq!(
+		OwningTree::from_events([Enter(1, 0), Exit(1, 0)]),
+		Some(OwningTree::new(1, Vec::new()))
+	);
+}
+
+#[test]
+fn tree_macro() {
+	let tree: OwningTree<u8> = crate::tree![0 => [1, 2 => [3, 4 => []], 5]];
+	assert_eq!(tree.children.len(), 3);
+	assert_eq!(tree.children[1].children[1], OwningTree::new(4, Vec::new()));
+	let contents: Vec<_> = tree.iter_dft_pre(()).cloned().collect();
+	assert_eq!(contents, [0, 1, 2, 3, 4, 5]);
+
+	// trailing commas and arbitrary expressions work too
+	let base = 10;
+	let tree: OwningTree<u8> = crate::tree![base + 1 => [base * 2, (base - 1) => [0],],];
+	let contents: Vec<_> = tree.iter_dft(()).cloned().collect();
+	assert_eq!(contents, [20, 0, 9, 11]);
+}
+
+#[test]
+fn tree_macro_many_children() {
+	let tree: OwningTree<u8> = crate::tree![0 => [
+		0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9,
+		0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9,
+		0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9,
+		0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9,
+		0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9,
+		0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9,
+		0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9,
+		0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9,
+		0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9,
+		0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9
+	]];
+	assert_eq!(tree.children.len(), 300);
+	assert_eq!(tree.children[299], OwningTree::new(9, Vec::new()));
+}
@@ -0,0 +1,232 @@
+//! Lowest common ancestor queries.
+//!
+//! There are four ways to find the lowest common ancestor of two nodes, depending on what you
+//! know about them and how many queries you need:
+//!
+//! * `Treelike::lca_by_path` compares two paths, works on any tree and needs no allocations.
+//! * [TreelikeParent::common_ancestor][crate::TreelikeParent::common_ancestor] walks up from
+//!   both nodes, for trees whose nodes know their parent.
+//! * `Treelike::lca_of` searches for both nodes, for trees whose nodes can be compared.
+//! * `LcaIndex` preprocesses the whole tree once and then answers each query in O(1).
+
+#[cfg(feature = "alloc")]
+use crate::Treelike;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Returns the longest common prefix of two paths, i.e. the path to their lowest common
+/// ancestor.
+pub fn common_prefix<'a>(a: &'a [usize], b: &[usize]) -> &'a [usize] {
+	let len = a.iter().zip(b).take_while(|(a, b)| a == b).count();
+	&a[..len]
+}
+
+/// Index over a tree answering lowest common ancestor, ancestor and distance queries in O(1).
+///
+/// Building the index takes O(n log n) time and memory for a tree of n nodes.
+///
+/// Nodes are identified by their number in a depth-first pre-order traversal, the root being 0.
+/// Use [id_of_path][LcaIndex::id_of_path] to find the id of a node and [node][LcaIndex::node]
+/// to get the node back.
+///
+/// Ids that are out of bounds cause a panic, like indexing a slice would.
+///
+/// # Example
+/// ```
+/// # use treelike::example::LinTree;
+/// use treelike::lca::LcaIndex;
+///
+/// let base = [0, 1, 2, 3, 4, 5, 6];
+/// let index = LcaIndex::new(LinTree::new(0, &base));
+///
+/// let a = index.id_of_path(&[0, 1]).unwrap();
+/// let b = index.id_of_path(&[0, 0]).unwrap();
+/// let lca = index.lca(a, b);
+/// assert_eq!(lca, index.id_of_path(&[0]).unwrap());
+/// assert_eq!(index.distance(a, b), 2);
+/// assert!(index.is_ancestor(lca, a));
+/// ```
+#[cfg(feature = "alloc")]
+pub struct LcaIndex<T> {
+	nodes: Vec<T>,
+	depth: Vec<usize>,
+	size: Vec<usize>,
+	/// first occurrence of each id in euler
+	first: Vec<usize>,
+	/// sparse[k][i] is the id with the lowest depth in euler[i..i + 2^k]
+	sparse: Vec<Vec<usize>>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Treelike> LcaIndex<T> {
+	pub fn new(root: T) -> Self {
+		let mut nodes = Vec::new();
+		let mut depth = Vec::new();
+		let mut size = Vec::new();
+		let mut first = Vec::new();
+		let mut euler = Vec::new();
+
+		// (id, children) of all nodes on the way from the root to the current one
+		let mut stack = Vec::new();
+		let mut next = Some(root);
+		loop {
+			if let Some(node) = next.take() {
+				let id = nodes.len();
+				nodes.push(node.clone());
+				depth.push(stack.len());
+				size.push(1);
+				first.push(euler.len());
+				euler.push(id);
+				stack.push((id, node.children()));
+			}
+
+			let (id, children) = match stack.last_mut() {
+				Some(top) => top,
+				None => break,
+			};
+			if let Some(child) = children.next() {
+				next = Some(child);
+			} else {
+				let id = *id;
+				stack.pop();
+				if let Some((parent, _)) = stack.last() {
+					size[*parent] += size[id];
+					euler.push(*parent);
+				}
+			}
+		}
+
+		let len = euler.len();
+		let mut sparse = alloc::vec![euler];
+		let mut width = 1;
+		while width * 2 <= len {
+			let prev = sparse.last().unwrap();
+			let row = (0..=len - width * 2)
+				.map(|i| shallower(&depth, prev[i], prev[i + width]))
+				.collect();
+			sparse.push(row);
+			width *= 2;
+		}
+
+		LcaIndex {
+			nodes,
+			depth,
+			size,
+			first,
+			sparse,
+		}
+	}
+
+	/// Amount of nodes in the tree.
+	pub fn len(&self) -> usize { self.nodes.len() }
+
+	/// Always false, as every tree has a root.
+	pub fn is_empty(&self) -> bool { self.nodes.is_empty() }
+
+	/// Returns the node with the given id.
+	pub fn node(&self, id: usize) -> Option<T> { self.nodes.get(id).cloned() }
+
+	/// Returns the id of the node at path, see [Treelike::get_by_path].
+	///
+	/// This takes O(path length * children) time.
+	pub fn id_of_path(&self, path: &[usize]) -> Option<usize> {
+		let mut id = 0;
+		for &index in path {
+			let end = id + self.size[id];
+			// the first child directly follows its parent, each sibling directly follows
+			// the subtree of the previous one
+			id += 1;
+			for _ in 0..index {
+				if id >= end {
+					return None;
+				}
+				id += self.size[id];
+			}
+			if id >= end {
+				return None;
+			}
+		}
+		Some(id)
+	}
+
+	/// Depth of the node, the root having depth 0.
+	pub fn depth(&self, id: usize) -> usize { self.depth[id] }
+
+	/// Returns the id of the lowest common ancestor of a and b.
+	///
+	/// A node counts as its own ancestor here, so if a is an ancestor of b the result is a.
+	pub fn lca(&self, a: usize, b: usize) -> usize {
+		let (a, b) = (self.first[a], self.first[b]);
+		let (l, r) = if a <= b { (a, b) } else { (b, a) };
+		let level = log2(r - l + 1);
+		let row = &self.sparse[level];
+		shallower(&self.depth, row[l], row[r + 1 - (1 << level)])
+	}
+
+	/// True if ancestor is a proper ancestor of node.
+	pub fn is_ancestor(&self, ancestor: usize, node: usize) -> bool {
+		ancestor < node && node < ancestor + self.size[ancestor]
+	}
+
+	/// Amount of edges on the path between a and b.
+	pub fn distance(&self, a: usize, b: usize) -> usize {
+		let lca = self.lca(a, b);
+		self.depth[a] + self.depth[b] - 2 * self.depth[lca]
+	}
+}
+
+#[cfg(feature = "alloc")]
+fn shallower(depth: &[usize], a: usize, b: usize) -> usize {
+	if depth[a] <= depth[b] { a } else { b }
+}
+
+/// flooring log2, n must not be 0
+#[cfg(feature = "alloc")]
+fn log2(n: usize) -> usize { (usize::BITS - 1 - n.leading_zeros()) as usize }
+
+#[cfg(feature = "alloc")]
+#[test]
+fn lca_index_test() {
+	use crate::{TreelikeParent, example::LinTree};
+	let base: Vec<usize> = (0..23).collect();
+	let root = LinTree::new(0, &base);
+	let index = LcaIndex::new(root);
+	assert_eq!(index.len(), base.len());
+
+	let paths: Vec<_> = root.iter_dft_pre_paths().map(|(path, _)| path).collect();
+	for (id, path) in paths.iter().enumerate() {
+		assert_eq!(index.id_of_path(path), Some(id));
+	}
+	assert_eq!(index.id_of_path(&[1, 1, 1, 0]), None);
+	assert_eq!(index.id_of_path(&[2]), None);
+
+	for a in 0..index.len() {
+		for b in 0..index.len() {
+			let na = index.node(a).unwrap();
+			let nb = index.node(b).unwrap();
+			let lca = index.node(index.lca(a, b)).unwrap();
+			assert_eq!(Some(lca), na.common_ancestor(nb));
+			assert_eq!(
+				Some(lca),
+				root.lca_by_path(&paths[a], &paths[b]),
+				"{} {}",
+				a,
+				b
+			);
+			assert_eq!(index.is_ancestor(a, b), na.is_ancestor_of(nb));
+			assert_eq!(Some(index.distance(a, b)), na.distance(nb));
+		}
+	}
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn lca_single_node() {
+	use crate::example::LinTree;
+	let base = [0];
+	let index = LcaIndex::new(LinTree::new(0, &base));
+	assert_eq!(index.lca(0, 0), 0);
+	assert_eq!(index.distance(0, 0), 0);
+	assert!(!index.is_ancestor(0, 0));
+	assert_eq!(index.id_of_path(&[0]), None);
+}
@@ -1,14 +1,19 @@
 #![cfg_attr(not(test), no_std)]
+#![allow(clippy::tabs_in_doc_comments)]
 
 //! This crate tries to provide a common trait for all kinds of trees. Two reasons for that:
 //!
 //! ## Interoperability
 //! Using a common trait allows third parties to switch tree implementations seamlessly. It also
 //! enables further abstractions to be built over for trees.
+//! The `adapter` module makes existing data traversable, like nested structs, directories
+//! (`std` feature) and documents (`serde_json`, `toml`, `serde_yaml` and `roxmltree` features).
 //!
 //! ## Automation
 //! If you are implementing a tree, `Treelike` only requires you to implement two methods on
 //! your nodes, `content` to return its contents and `children` to list its children.
+//! With the `derive` feature enabled `#[derive(Treelike)]` does that for you, see the
+//! `container` module for the supported ways of storing children.
 //!
 //! Many kinds of traversals and searches are then provided for free. I found myself implementing
 //! the same methods over and over on different trees, so that is my main motivation.
@@ -21,6 +26,8 @@
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 pub mod treelike;
 pub use crate::treelike::Treelike;
@@ -28,4 +35,43 @@ pub use crate::treelike::Treelike;
 pub mod treelike_mut;
 pub use crate::treelike_mut::TreelikeMut;
 
+pub mod treelike_parent;
+pub use crate::treelike_parent::TreelikeParent;
+
+pub mod dyn_treelike;
+pub use crate::dyn_treelike::DynTreelike;
+
+pub mod try_treelike;
+pub use crate::try_treelike::TryTreelike;
+
+pub mod simple;
+
+pub mod builder;
+
+pub mod unfold;
+
+pub mod container;
+#[cfg(feature = "derive")]
+pub use treelike_derive::{Treelike, TreelikeMut};
+
+pub mod cursor;
+
+pub mod context;
+
+pub mod events;
+
+#[cfg(feature = "alloc")]
+pub mod path;
+
+pub mod lca;
+
+#[cfg(feature = "alloc")]
+pub mod numbering;
+
+pub mod visitor;
+
+pub mod parts;
+
+pub mod adapter;
+
 pub mod example;
@@ -0,0 +1,204 @@
+//! Pre- and post-order numbering of all nodes of a tree.
+//!
+//! Walking a tree once and remembering where each node was visited turns many structural
+//! questions into comparisons of numbers:
+//!
+//! * a is an ancestor of b if a is visited before b in pre-order and after b in post-order.
+//! * the subtree of a node is a contiguous range in pre-order.
+//!
+//! [Numbering] stores these numbers in a side table keyed either by [TreePath] or by a key you
+//! derive from each node.
+
+use crate::{Treelike, path::TreePath};
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::{borrow::Borrow, iter::Enumerate, ops::Range};
+
+/// Where a single node was visited, see [Numbering].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NodeNumbers {
+	/// Position in a depth-first pre-order traversal, the root is 0.
+	pub pre: usize,
+	/// Position in a depth-first post-order traversal, the root is the last one.
+	pub post: usize,
+	/// Amount of nodes in the subtree, including this node.
+	pub size: usize,
+	/// Distance to the root, the root has depth 0.
+	pub depth: usize,
+}
+
+impl NodeNumbers {
+	/// True if this node is a proper ancestor of other.
+	pub fn is_ancestor_of(&self, other: &NodeNumbers) -> bool {
+		self.pre < other.pre && other.post < self.post
+	}
+
+	/// The pre-order numbers of all nodes in this subtree.
+	pub fn subtree(&self) -> Range<usize> { self.pre..self.pre + self.size }
+}
+
+/// Side table of [NodeNumbers] for each node of a tree.
+///
+/// # Example
+/// ```
+/// # use treelike::example::LinTree;
+/// # use treelike::Treelike;
+/// use treelike::numbering::Numbering;
+///
+/// let base = [0, 1, 2, 3, 4, 5, 6];
+/// let numbering = Numbering::by_key(LinTree::new(0, &base), |node| *node.content());
+///
+/// assert!(numbering.is_ancestor(&1, &4).unwrap());
+/// assert!(!numbering.is_ancestor(&2, &4).unwrap());
+/// assert_eq!(numbering.subtree(&1).unwrap(), &[1, 3, 4]);
+///
+/// let numbering = Numbering::by_path(LinTree::new(0, &base));
+/// assert_eq!(numbering.get(&[1, 0][..]).unwrap().pre, 5);
+/// ```
+pub struct Numbering<K> {
+	/// keys in pre-order
+	keys: Vec<K>,
+	/// numbers in pre-order
+	numbers: Vec<NodeNumbers>,
+	lookup: BTreeMap<K, usize>,
+}
+
+impl<K: Ord + Clone> Numbering<K> {
+	/// Numbers all nodes below root, using key to identify them.
+	///
+	/// Keys should be unique, if they are not only the last node with a given key can be looked
+	/// up.
+	pub fn by_key<T: Treelike>(root: T, mut key: impl FnMut(T) -> K) -> Self {
+		Self::build(root, |node, _| key(node))
+	}
+
+	/// Returns the numbers of the node with key.
+	pub fn get<Q: Ord + ?Sized>(&self, key: &Q) -> Option<&NodeNumbers>
+	where
+		K: Borrow<Q>,
+	{
+		self.lookup.get(key).map(|&pre| &self.numbers[pre])
+	}
+
+	/// True if ancestor is a proper ancestor of node, [None] if one of the keys is unknown.
+	pub fn is_ancestor<Q: Ord + ?Sized>(&self, ancestor: &Q, node: &Q) -> Option<bool>
+	where
+		K: Borrow<Q>,
+	{
+		Some(self.get(ancestor)?.is_ancestor_of(self.get(node)?))
+	}
+
+	/// The keys of all nodes in the subtree of key, in pre-order, starting with key itself.
+	pub fn subtree<Q: Ord + ?Sized>(&self, key: &Q) -> Option<&[K]>
+	where
+		K: Borrow<Q>,
+	{
+		Some(&self.keys[self.get(key)?.subtree()])
+	}
+
+	/// The keys of all nodes in pre-order.
+	pub fn preorder(&self) -> &[K] { &self.keys }
+
+	/// Iterates over all keys and their numbers, in pre-order.
+	pub fn iter(&self) -> impl Iterator<Item = (&K, &NodeNumbers)> {
+		self.keys.iter().zip(self.numbers.iter())
+	}
+
+	/// Amount of nodes in the tree.
+	pub fn len(&self) -> usize { self.keys.len() }
+
+	/// Always false, as every tree has a root.
+	pub fn is_empty(&self) -> bool { self.keys.is_empty() }
+
+	fn build<T: Treelike>(root: T, mut key: impl FnMut(T, &[usize]) -> K) -> Self {
+		let mut keys = Vec::new();
+		let mut numbers = Vec::new();
+		let mut path = Vec::new();
+		let mut post = 0;
+
+		// like DFT, but every stack entry also remembers the pre-order number of its node
+		let mut stack: Vec<(usize, Enumerate<T::ChildIterator>)> = Vec::new();
+		let mut next = Some(root);
+		loop {
+			if let Some(node) = next.take() {
+				let pre = keys.len();
+				keys.push(key(node.clone(), &path));
+				numbers.push(NodeNumbers {
+					pre,
+					post: 0,
+					size: 0,
+					depth: stack.len(),
+				});
+				stack.push((pre, node.children().enumerate()));
+			}
+
+			let (pre, children) = match stack.last_mut() {
+				Some(top) => top,
+				None => break,
+			};
+			if let Some((index, child)) = children.next() {
+				path.truncate(stack.len() - 1);
+				path.push(index);
+				next = Some(child);
+			} else {
+				let numbers = &mut numbers[*pre];
+				numbers.post = post;
+				numbers.size = keys.len() - *pre;
+				post += 1;
+				stack.pop();
+			}
+		}
+
+		let lookup = keys
+			.iter()
+			.enumerate()
+			.map(|(pre, key)| (key.clone(), pre))
+			.collect();
+		Numbering {
+			keys,
+			numbers,
+			lookup,
+		}
+	}
+}
+
+impl Numbering<TreePath> {
+	/// Numbers all nodes below root, identifying them by their path.
+	pub fn by_path<T: Treelike>(root: T) -> Self { Self::build(root, |_, path| path.into()) }
+}
+
+#[test]
+fn numbering_test() {
+	use crate::example::LinTree;
+	let base: Vec<usize> = (0..20).collect();
+	let root = LinTree::new(0, &base);
+	let by_path = Numbering::by_path(root);
+	let by_key = Numbering::by_key(root, |node| *node.content());
+	assert_eq!(by_path.len(), base.len());
+
+	let pre: Vec<_> = root.iter_dft_pre(()).collect();
+	let post: Vec<_> = root.iter_dft(()).collect();
+	for (path, numbers) in by_path.iter() {
+		let content = root.get_by_path(path).unwrap().content();
+		assert_eq!(pre[numbers.pre], content);
+		assert_eq!(post[numbers.post], content);
+		assert_eq!(numbers.depth, path.depth());
+		assert_eq!(by_key.get(content), Some(numbers));
+
+		let mut size = 0;
+		root.get_by_path(path)
+			.unwrap()
+			.callback_dft(|_, _| size += 1, ());
+		assert_eq!(numbers.size, size);
+
+		for (other, other_numbers) in by_path.iter() {
+			assert_eq!(
+				numbers.is_ancestor_of(other_numbers),
+				path.is_ancestor_of(other)
+			);
+		}
+	}
+
+	assert_eq!(by_key.subtree(&2).unwrap(), &[2, 5, 11, 12, 6, 13, 14]);
+	assert_eq!(by_key.subtree(&20), None);
+	assert_eq!(by_key.is_ancestor(&0, &20), None);
+}
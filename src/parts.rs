@@ -0,0 +1,284 @@
+//! Traversals over specific parts of a tree: leaves, internal nodes, edges and root-to-leaf paths.
+//!
+//! All of these are depth-first pre-order and respect the filter they are given, so a node whose
+//! children are all filtered out counts as a leaf. Each nodes children are only enumerated once.
+
+use crate::{Treelike, treelike::FilterBuilder};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::iter::Peekable;
+
+pub(crate) fn callback_leaves<T: Treelike, CB: FnMut(T::Content, usize), F: FilterBuilder<T>>(
+	t: T,
+	mut cb: CB,
+	f: F,
+	depth: usize,
+	leaves: bool,
+) -> CB {
+	let mut children = f
+		.build(t.clone().content(), depth, t.clone().children())
+		.peekable();
+	if (children.peek().is_none()) == leaves {
+		cb(t.content(), depth);
+	}
+	for child in children {
+		cb = callback_leaves(child, cb, f, depth + 1, leaves);
+	}
+	cb
+}
+
+pub(crate) fn callback_edges<
+	T: Treelike,
+	CB: FnMut(T::Content, T::Content, usize),
+	F: FilterBuilder<T>,
+>(
+	t: T,
+	mut cb: CB,
+	f: F,
+	depth: usize,
+) -> CB {
+	for child in f.build(t.clone().content(), depth, t.clone().children()) {
+		cb(t.clone().content(), child.clone().content(), depth + 1);
+		cb = callback_edges(child, cb, f, depth + 1);
+	}
+	cb
+}
+
+#[cfg(feature = "alloc")]
+pub(crate) fn callback_paths<T: Treelike, CB: FnMut(&[T::Content]), F: FilterBuilder<T>>(
+	t: T,
+	mut cb: CB,
+	f: F,
+	path: &mut Vec<T::Content>,
+) -> CB {
+	let depth = path.len();
+	path.push(t.clone().content());
+	let mut children = f.build(t.clone().content(), depth, t.children()).peekable();
+	if children.peek().is_none() {
+		cb(path);
+	}
+	for child in children {
+		cb = callback_paths(child, cb, f, path);
+	}
+	path.pop();
+	cb
+}
+
+/// A node as seen by [Walk].
+#[cfg(feature = "alloc")]
+struct Step<T> {
+	node: T,
+	parent: Option<T>,
+	depth: usize,
+	leaf: bool,
+}
+
+/// Pre-order traversal that knows whether a node is a leaf before descending into it.
+/// The stack contains all nodes from the root to the current one.
+#[cfg(feature = "alloc")]
+struct Walk<T: Treelike, F: FilterBuilder<T>> {
+	stack: Vec<(T, Peekable<F::Filter>)>,
+	filter: F,
+	next: Option<T>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Treelike, F: FilterBuilder<T>> Walk<T, F> {
+	fn new(treelike: T, filter: F) -> Self {
+		Walk {
+			stack: Vec::new(),
+			filter,
+			next: Some(treelike),
+		}
+	}
+
+	fn step(&mut self) -> Option<Step<T>> {
+		loop {
+			if let Some(node) = self.next.take() {
+				let depth = self.stack.len();
+				let mut children = self
+					.filter
+					.build(node.clone().content(), depth, node.clone().children())
+					.peekable();
+				let leaf = children.peek().is_none();
+				let parent = self.stack.last().map(|(parent, _)| parent.clone());
+				self.stack.push((node.clone(), children));
+				return Some(Step {
+					node,
+					parent,
+					depth,
+					leaf,
+				});
+			}
+
+			let (_, children) = self.stack.last_mut()?;
+			match children.next() {
+				Some(child) => self.next = Some(child),
+				None => {
+					self.stack.pop();
+				},
+			}
+		}
+	}
+}
+
+/// Iterator over the contents of all leaves, see [iter_leaves][Treelike::iter_leaves].
+#[cfg(feature = "alloc")]
+pub struct Leaves<T: Treelike, F: FilterBuilder<T>>(Walk<T, F>);
+
+#[cfg(feature = "alloc")]
+impl<T: Treelike, F: FilterBuilder<T>> Leaves<T, F> {
+	pub(crate) fn new(treelike: T, filter: F) -> Self { Leaves(Walk::new(treelike, filter)) }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Treelike, F: FilterBuilder<T>> Iterator for Leaves<T, F> {
+	type Item = T::Content;
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			let step = self.0.step()?;
+			if step.leaf {
+				return Some(step.node.content());
+			}
+		}
+	}
+}
+
+/// Iterator over the contents of all nodes with children, see
+/// [iter_internal_nodes][Treelike::iter_internal_nodes].
+#[cfg(feature = "alloc")]
+pub struct InternalNodes<T: Treelike, F: FilterBuilder<T>>(Walk<T, F>);
+
+#[cfg(feature = "alloc")]
+impl<T: Treelike, F: FilterBuilder<T>> InternalNodes<T, F> {
+	pub(crate) fn new(treelike: T, filter: F) -> Self { InternalNodes(Walk::new(treelike, filter)) }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Treelike, F: FilterBuilder<T>> Iterator for InternalNodes<T, F> {
+	type Item = T::Content;
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			let step = self.0.step()?;
+			if !step.leaf {
+				return Some(step.node.content());
+			}
+		}
+	}
+}
+
+/// Iterator over `(parent, child)` content pairs, see [iter_edges][Treelike::iter_edges].
+#[cfg(feature = "alloc")]
+pub struct Edges<T: Treelike, F: FilterBuilder<T>>(Walk<T, F>);
+
+#[cfg(feature = "alloc")]
+impl<T: Treelike, F: FilterBuilder<T>> Edges<T, F> {
+	pub(crate) fn new(treelike: T, filter: F) -> Self { Edges(Walk::new(treelike, filter)) }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Treelike, F: FilterBuilder<T>> Iterator for Edges<T, F> {
+	type Item = (T::Content, T::Content);
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			let step = self.0.step()?;
+			if let Some(parent) = step.parent {
+				return Some((parent.content(), step.node.content()));
+			}
+		}
+	}
+}
+
+/// Iterator over the contents on the way from the root to each leaf, see
+/// [iter_root_to_leaf_paths][Treelike::iter_root_to_leaf_paths].
+#[cfg(feature = "alloc")]
+pub struct RootToLeafPaths<T: Treelike, F: FilterBuilder<T>>(Walk<T, F>);
+
+#[cfg(feature = "alloc")]
+impl<T: Treelike, F: FilterBuilder<T>> RootToLeafPaths<T, F> {
+	pub(crate) fn new(treelike: T, filter: F) -> Self {
+		RootToLeafPaths(Walk::new(treelike, filter))
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Treelike, F: FilterBuilder<T>> Iterator for RootToLeafPaths<T, F> {
+	type Item = Vec<T::Content>;
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			let step = self.0.step()?;
+			if step.leaf {
+				debug_assert_eq!(step.depth + 1, self.0.stack.len());
+				return Some(
+					self.0
+						.stack
+						.iter()
+						.map(|(node, _)| node.clone().content())
+						.collect(),
+				);
+			}
+		}
+	}
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn parts_test() {
+	use crate::{example::LinTree, treelike::MaxDepth};
+	let base = [0, 1, 2, 3, 4, 5, 6, 7];
+	let root = LinTree::new(0, &base);
+
+	let leaves: Vec<_> = root.iter_leaves(()).cloned().collect();
+	assert_eq!(leaves, [7, 4, 5, 6]);
+	let mut callback = Vec::new();
+	root.callback_leaves(|c, _| callback.push(*c), ());
+	assert_eq!(leaves, callback);
+
+	let internal: Vec<_> = root.iter_internal_nodes(()).cloned().collect();
+	assert_eq!(internal, [0, 1, 3, 2]);
+	let mut callback = Vec::new();
+	root.callback_internal_nodes(|c, _| callback.push(*c), ());
+	assert_eq!(internal, callback);
+
+	let edges: Vec<_> = root.iter_edges(()).map(|(p, c)| (*p, *c)).collect();
+	assert_eq!(edges, [
+		(0, 1),
+		(1, 3),
+		(3, 7),
+		(1, 4),
+		(0, 2),
+		(2, 5),
+		(2, 6)
+	]);
+	let mut callback = Vec::new();
+	root.callback_edges(|p, c, _| callback.push((*p, *c)), ());
+	assert_eq!(edges, callback);
+
+	let paths: Vec<Vec<_>> = root
+		.iter_root_to_leaf_paths(())
+		.map(|p| p.into_iter().cloned().collect())
+		.collect();
+	assert_eq!(paths, [
+		alloc::vec![0, 1, 3, 7],
+		alloc::vec![0, 1, 4],
+		alloc::vec![0, 2, 5],
+		alloc::vec![0, 2, 6],
+	]);
+	let mut callback: Vec<Vec<_>> = Vec::new();
+	root.callback_root_to_leaf_paths(|p| callback.push(p.iter().map(|c| **c).collect()), ());
+	assert_eq!(paths, callback);
+
+	// filtered out children turn their parents into leaves
+	let leaves: Vec<_> = root.iter_leaves(MaxDepth::new(1)).cloned().collect();
+	assert_eq!(leaves, [1, 2]);
+	let internal: Vec<_> = root
+		.iter_internal_nodes(MaxDepth::new(1))
+		.cloned()
+		.collect();
+	assert_eq!(internal, [0]);
+	assert_eq!(root.iter_edges(MaxDepth::new(0)).count(), 0);
+	let single = LinTree::new(7, &base);
+	let paths: Vec<_> = single.iter_root_to_leaf_paths(()).collect();
+	assert_eq!(paths, [[&7]]);
+}
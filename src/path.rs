@@ -0,0 +1,164 @@
+//! Addressing nodes by the child indices leading to them from the root.
+//!
+//! [Treelike::get_by_path] resolves a path back to a node and does not need allocations.
+//! [TreePath] is an owned path that can be stored, compared and printed,
+//! [iter_dft_pre_paths][Treelike::iter_dft_pre_paths] produces one for each visited node.
+
+use crate::Treelike;
+use alloc::vec::Vec;
+use core::{borrow::Borrow, fmt, iter::Enumerate, ops::Deref};
+
+/// An owned path from a root to a node, as the index of the child taken on each level.
+///
+/// The empty path points to the root.
+///
+/// Paths are ordered in document order, i.e. the order of a depth-first pre-order traversal:
+/// parents come before their children and left siblings before right ones.
+///
+/// They are printed as the indices separated by `/`, the root is printed as `/`.
+/// ```
+/// # use treelike::path::TreePath;
+/// let path = TreePath::from(vec![0, 2]);
+/// assert_eq!(path.to_string(), "/0/2");
+/// assert!(path.parent().unwrap() < path);
+/// assert!(path < TreePath::from(vec![1]));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TreePath(Vec<usize>);
+
+impl TreePath {
+	/// The path to the root.
+	pub fn root() -> Self { Self::default() }
+
+	/// Amount of steps from the root, i.e. the depth of the node pointed to.
+	pub fn depth(&self) -> usize { self.0.len() }
+
+	/// Extends the path by one level.
+	pub fn push(&mut self, index: usize) { self.0.push(index) }
+
+	/// Removes the last level, returning its index.
+	pub fn pop(&mut self) -> Option<usize> { self.0.pop() }
+
+	/// Returns the path to the parent, or [None] if this points to the root.
+	pub fn parent(&self) -> Option<Self> {
+		let (_, parent) = self.0.split_last()?;
+		Some(Self(parent.to_vec()))
+	}
+
+	/// Returns a new path to the child at index.
+	pub fn child(&self, index: usize) -> Self {
+		let mut child = self.clone();
+		child.push(index);
+		child
+	}
+
+	/// True if other is somewhere below this path. A path is not its own ancestor.
+	pub fn is_ancestor_of(&self, other: &TreePath) -> bool {
+		other.0.len() > self.0.len() && other.0.starts_with(&self.0)
+	}
+}
+
+impl Deref for TreePath {
+	type Target = [usize];
+	fn deref(&self) -> &[usize] { &self.0 }
+}
+
+impl Borrow<[usize]> for TreePath {
+	fn borrow(&self) -> &[usize] { &self.0 }
+}
+
+impl From<Vec<usize>> for TreePath {
+	fn from(v: Vec<usize>) -> Self { Self(v) }
+}
+
+impl From<&[usize]> for TreePath {
+	fn from(s: &[usize]) -> Self { Self(s.to_vec()) }
+}
+
+impl From<TreePath> for Vec<usize> {
+	fn from(p: TreePath) -> Self { p.0 }
+}
+
+impl fmt::Display for TreePath {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if self.0.is_empty() {
+			return f.write_str("/");
+		}
+		for index in &self.0 {
+			write!(f, "/{}", index)?;
+		}
+		Ok(())
+	}
+}
+
+/// Depth-first pre-order traversal that also yields the [TreePath] of each node.
+///
+/// Created by [iter_dft_pre_paths][Treelike::iter_dft_pre_paths].
+pub struct PathDFTP<T: Treelike> {
+	stack: Vec<Enumerate<T::ChildIterator>>,
+	path: TreePath,
+	cur: Option<T>,
+}
+
+impl<T: Treelike> PathDFTP<T> {
+	pub(crate) fn new(treelike: T) -> Self {
+		Self {
+			stack: Vec::new(),
+			path: TreePath::root(),
+			cur: Some(treelike),
+		}
+	}
+}
+
+impl<T: Treelike> Iterator for PathDFTP<T> {
+	type Item = (TreePath, T::Content);
+	fn next(&mut self) -> Option<Self::Item> {
+		if let Some(node) = self.cur.take() {
+			self.stack.push(node.clone().children().enumerate());
+			return Some((self.path.clone(), node.content()));
+		}
+		loop {
+			let depth = self.stack.len().checked_sub(1)?;
+			if let Some((index, child)) = self.stack[depth].next() {
+				self.path.0.truncate(depth);
+				self.path.push(index);
+				self.cur = Some(child);
+				return self.next();
+			}
+			self.stack.pop();
+		}
+	}
+}
+
+#[test]
+fn path_test() {
+	use crate::example::LinTree;
+	let base = [0, (1), 2, (3), 4, 5, 6, (7), 8, 9, 10, 11, 12, 13, 14, (15)];
+	let root = LinTree::new(0, &base);
+
+	let with_paths: Vec<_> = root.iter_dft_pre_paths().collect();
+	let plain: Vec<_> = root.iter_dft_pre(()).collect();
+	assert_eq!(with_paths.len(), plain.len());
+
+	let mut last: Option<TreePath> = None;
+	for ((path, content), expected) in with_paths.iter().zip(plain) {
+		assert_eq!(*content, expected);
+		assert_eq!(
+			root.get_by_path(path).map(Treelike::content),
+			Some(*content)
+		);
+		if let Some(last) = last {
+			assert!(last < *path);
+		}
+		last = Some(path.clone());
+	}
+
+	assert_eq!(with_paths[4].0, TreePath::from(alloc::vec![0, 0, 0, 0]));
+	assert_eq!(with_paths[4].0.to_string(), "/0/0/0/0");
+	assert_eq!(TreePath::root().to_string(), "/");
+	assert!(TreePath::root().is_ancestor_of(&with_paths[4].0));
+	assert!(!with_paths[4].0.is_ancestor_of(&with_paths[4].0));
+
+	assert!(root.get_by_path(&[1, 1, 1, 0]).is_none());
+	assert!(root.get_by_path(&[2]).is_none());
+}
@@ -0,0 +1,130 @@
+//! A version of [Treelike][crate::Treelike] that is easier to implement.
+//!
+//! Implementing [Treelike][crate::Treelike] means spelling out the type of the child iterator,
+//! which gets long quickly and forces closures into free functions. [SimpleTreelike] returns
+//! `impl Iterator` instead, so any iterator chain works.
+//!
+//! The opaque iterator type can not be named, so the traversals are reached through `Bridge`,
+//! which collects the children of each node into a `Vec`. That is one allocation per visited
+//! node, even in the `callback_*` traversals that otherwise don't allocate, and it means
+//! `Bridge` is only available with the `alloc` feature. Implement
+//! [Treelike][crate::Treelike] directly where that matters.
+#![cfg_attr(feature = "alloc", doc = "```")]
+#![cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+//! use treelike::{
+//! 	Treelike,
+//! 	simple::{Bridge, SimpleTreelike},
+//! };
+//!
+//! struct Node {
+//! 	content: u8,
+//! 	children: Vec<Option<Box<Node>>>,
+//! }
+//!
+//! impl<'a> SimpleTreelike for &'a Node {
+//! 	type Content = u8;
+//!
+//! 	fn content(self) -> Self::Content { self.content }
+//!
+//! 	fn children(self) -> impl Iterator<Item = Self> {
+//! 		self.children.iter().flatten().map(|child| &**child)
+//! 	}
+//! }
+//!
+//! let root = Node {
+//! 	content: 0,
+//! 	children: vec![
+//! 		Some(Box::new(Node {
+//! 			content: 1,
+//! 			children: vec![],
+//! 		})),
+//! 		None,
+//! 		Some(Box::new(Node {
+//! 			content: 2,
+//! 			children: vec![],
+//! 		})),
+//! 	],
+//! };
+//!
+//! let contents: Vec<_> = Bridge(&root).iter_dft_pre(()).collect();
+//! assert_eq!(contents, [0, 1, 2]);
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Like [Treelike][crate::Treelike], but [children][SimpleTreelike::children] may return any iterator.
+///
+/// See the [module documentation][self] for how to use it.
+pub trait SimpleTreelike: Sized + Clone {
+	/// The content of the current node, see [Content][crate::Treelike::Content].
+	type Content;
+
+	/// Has to produce this nodes [Content][SimpleTreelike::Content].
+	fn content(self) -> Self::Content;
+
+	/// Has to return an Iterator over all this nodes direct children.
+	fn children(self) -> impl Iterator<Item = Self>;
+}
+
+/// Makes a [SimpleTreelike] usable as a [Treelike][crate::Treelike].
+///
+/// # no_std note
+/// Each call to [children][crate::Treelike::children] collects the children into a [Vec], so every
+/// traversal allocates once per node it descends into, including the `callback_*` ones. This is
+/// why it needs the `alloc` feature.
+///
+/// The iterator returned by [SimpleTreelike::children] can not be named, so it can not be
+/// stored lazily instead.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Bridge<T>(pub T);
+
+#[cfg(feature = "alloc")]
+impl<T: SimpleTreelike> crate::Treelike for Bridge<T> {
+	type Content = T::Content;
+
+	fn content(self) -> Self::Content { self.0.content() }
+
+	type ChildIterator = alloc::vec::IntoIter<Self>;
+
+	fn children(self) -> Self::ChildIterator {
+		self.0
+			.children()
+			.map(Bridge)
+			.collect::<Vec<_>>()
+			.into_iter()
+	}
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn bridge_test() {
+	use crate::{
+		Treelike,
+		example::{LinTree, OwningTree},
+	};
+
+	#[derive(Clone, Copy)]
+	struct Simple<'a>(&'a OwningTree<usize>);
+
+	impl<'a> SimpleTreelike for Simple<'a> {
+		type Content = &'a usize;
+
+		fn content(self) -> Self::Content { &self.0.content }
+
+		fn children(self) -> impl Iterator<Item = Self> { self.0.children.iter().map(Simple) }
+	}
+
+	let base: Vec<usize> = (0..20).collect();
+	let events = LinTree::new(0, &base)
+		.iter_events(())
+		.map(|e| e.map(|c| *c));
+	let tree = OwningTree::from_events(events).unwrap();
+	let bridge = Bridge(Simple(&tree));
+
+	assert!(tree.iter_dft(()).eq(bridge.iter_dft(())));
+	assert!(tree.iter_bft(()).eq(bridge.iter_bft(())));
+	assert!(tree.iter_dft_rev(()).eq(bridge.iter_dft_rev(())));
+	assert_eq!(tree.level(3), bridge.level(3));
+}
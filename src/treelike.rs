@@ -1,4 +1,18 @@
 #[cfg(feature = "alloc")]
+use crate::path::{PathDFTP, TreePath};
+use crate::{
+	context::{self, NodeContext},
+	events::{self, Event},
+	lca::common_prefix,
+	parts,
+	visitor::{self, TreeVisitor},
+};
+#[cfg(feature = "alloc")]
+use crate::{
+	events::Events,
+	parts::{Edges, InternalNodes, Leaves, RootToLeafPaths},
+};
+#[cfg(feature = "alloc")]
 use alloc::collections::VecDeque;
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
@@ -10,8 +24,12 @@ use alloc::vec::Vec;
 /// only [children][Treelike::children] and [content][Treelike::content] need to be implemented.
 ///
 /// Should probably be implemented on references of the node-type,
-/// unless your node itself is already [Copy]. See [LinTree][crate::example::LinTree] for an example of
-/// that.
+/// unless your node itself is already cheap to [Clone]. See [LinTree][crate::example::LinTree]
+/// for an example of that.
+///
+/// Nodes are passed around by value and cloned a lot, so cloning them should be cheap.
+/// Handles like `Rc` are fine, see `RcTree` and `ArcTree` in
+/// [example][crate::example].
 ///
 /// # no_std note
 /// The `callback_*` functions allow operating on the trees without allocations in a
@@ -28,7 +46,7 @@ use alloc::vec::Vec;
 /// # Traversals and searches
 /// Most traversals take a Filter attribute. By passing () you get a pure traversal. By filtering
 /// you get a search.
-pub trait Treelike: Sized + Copy {
+pub trait Treelike: Sized + Clone {
 	/// The content of the current node.
 	///
 	/// If the node does not always contain something
@@ -55,22 +73,143 @@ pub trait Treelike: Sized + Copy {
 	/// Has to produce this nodes [Content][Treelike::Content].
 	fn content(self) -> Self::Content;
 
-    /// Finds the content of a leaf node based on a given traversal without backtracking.
-    fn leaf_by(mut self, mut f: impl FnMut(Self) -> Option<Self>) -> Self::Content {
-        while let Some(next) = f(self) {
-            self = next;
-        }
-        self.content()
-    }
+	/// Finds the content of a leaf node based on a given traversal without backtracking.
+	fn leaf_by(mut self, mut f: impl FnMut(Self) -> Option<Self>) -> Self::Content {
+		while let Some(next) = f(self.clone()) {
+			self = next;
+		}
+		self.content()
+	}
 
 	/// Recursively traverses the tree to the very first/leftmost node.
-	fn first(self) -> Self::Content {
-        self.leaf_by(Self::left)
-	}
+	fn first(self) -> Self::Content { self.leaf_by(Self::left) }
 
 	/// Recursively traverses the tree to the very last/rightmost node.
-	fn last(self) -> Self::Content {
-        self.leaf_by(Self::right)
+	fn last(self) -> Self::Content { self.leaf_by(Self::right) }
+
+	/// Follows path from this node, taking the child at the given index on each level.
+	///
+	/// Returns [None] if any of the indices is out of bounds. The empty path returns this node.
+	/// `TreePath` dereferences to a slice and can be passed here.
+	///
+	/// ```
+	/// # use treelike::example::LinTree;
+	/// # use treelike::Treelike;
+	/// let base = [3, 4, 5, 6, 7];
+	/// let node = LinTree::new(0, &base);
+	///
+	/// assert_eq!(node.get_by_path(&[0, 1]).map(Treelike::content), Some(&7));
+	/// assert!(node.get_by_path(&[1, 0]).is_none());
+	/// ```
+	fn get_by_path(self, path: &[usize]) -> Option<Self> {
+		path.iter()
+			.try_fold(self, |node, &index| node.children().nth(index))
+	}
+
+	/// Returns the lowest common ancestor of the nodes at paths a and b, or [None] if one of the
+	/// paths does not exist. See also [lca][crate::lca].
+	fn lca_by_path(self, a: &[usize], b: &[usize]) -> Option<Self> {
+		let common = common_prefix(a, b);
+		let lca = self.get_by_path(common)?;
+		lca.clone().get_by_path(&a[common.len()..])?;
+		lca.clone().get_by_path(&b[common.len()..])?;
+		Some(lca)
+	}
+
+	/// Searches for target below this node and returns the path to it.
+	///
+	/// This is a depth-first search, so it takes O(n) time. Nodes are compared using
+	/// [PartialEq], which should compare identity, not content.
+	#[cfg(feature = "alloc")]
+	fn path_to(self, target: Self) -> Option<TreePath>
+	where
+		Self: PartialEq,
+	{
+		let mut path = TreePath::root();
+		if path_to(self, target, &mut path) {
+			Some(path)
+		} else {
+			None
+		}
+	}
+
+	/// Returns the lowest common ancestor of a and b, or [None] if one of them is not part of
+	/// this tree.
+	///
+	/// This searches for both nodes, if you need many queries on the same tree use
+	/// [LcaIndex][crate::lca::LcaIndex] instead.
+	#[cfg(feature = "alloc")]
+	fn lca_of(self, a: Self, b: Self) -> Option<Self>
+	where
+		Self: PartialEq,
+	{
+		let a = self.clone().path_to(a)?;
+		let b = self.clone().path_to(b)?;
+		self.lca_by_path(&a, &b)
+	}
+
+	/// Turns a closure into a filter for this tree.
+	///
+	/// The closure gets the content and depth of a node and one of its children, and decides
+	/// whether that child is visited. This method only tells the compiler the types of the
+	/// closures arguments, so it does not matter which node of the tree it is called on.
+	fn child_filter<P: Fn(&Self::Content, usize, &Self) -> bool + Copy>(
+		self,
+		predicate: P,
+	) -> ChildFilter<P> {
+		ChildFilter(predicate)
+	}
+
+	/// Like [child_filter][Treelike::child_filter] but the closure gets the [NodeContext] of the
+	/// node instead of only its depth.
+	///
+	/// The context is only known in the `*_ctx` traversals, everywhere else the node is treated
+	/// as the only child of its parent.
+	///
+	/// ```
+	/// # use treelike::example::LinTree;
+	/// # use treelike::Treelike;
+	/// let base = [0, 1, 2, 3, 4, 5, 6];
+	/// let node = LinTree::new(0, &base);
+	///
+	/// // only the last child of each node has children of its own
+	/// let last = node.child_filter_ctx(|_content, context, _child| context.is_last);
+	/// let mut visited = Vec::new();
+	/// node.callback_dft_pre_ctx(|content, _context| visited.push(*content), last);
+	/// assert_eq!(visited, [0, 1, 2, 5, 6]);
+	/// ```
+	fn child_filter_ctx<P: Fn(&Self::Content, NodeContext, &Self) -> bool + Copy>(
+		self,
+		predicate: P,
+	) -> ChildFilterCtx<P> {
+		ChildFilterCtx(predicate)
+	}
+
+	/// Turns a closure that replaces the children of a node into a filter for this tree.
+	///
+	/// The closure gets the content and depth of a node and its children, and returns the
+	/// children to visit. Like [child_filter][Treelike::child_filter] the node does not matter.
+	///
+	/// ```
+	/// # use treelike::example::LinTree;
+	/// # use treelike::Treelike;
+	/// let base = [0, 1, 2, 3, 4, 5, 6];
+	/// let node = LinTree::new(0, &base);
+	///
+	/// // only the first child of each node
+	/// let first = node.replace_children(|_content, _depth, children| children.take(1));
+	/// let mut visited = Vec::new();
+	/// node.callback_dft_pre(|content, _depth| visited.push(*content), first);
+	/// assert_eq!(visited, [0, 1, 3]);
+	/// ```
+	fn replace_children<
+		I: Iterator<Item = Self>,
+		R: Fn(Self::Content, usize, Self::ChildIterator) -> I + Copy,
+	>(
+		self,
+		replace: R,
+	) -> ReplaceChildren<R> {
+		ReplaceChildren(replace)
 	}
 
 	/// Traverses the tree depth first, post order,
@@ -93,12 +232,12 @@ pub trait Treelike: Sized + Copy {
 	/// node.callback_dft(
 	/// 	|content, depth| {
 	/// 		dbg!((content, depth));
-	/// 		},
+	/// 	},
 	/// 	(),
-	/// 	)
+	/// )
 	/// ```
 	///
-	/// Pass an  `Fn(Self::Content, depth: usize, child: Self) -> bool` to filter.
+	/// Pass a closure through [child_filter][Treelike::child_filter] to filter.
 	/// For example stop at depth 1 and nodes with content 4:
 	///
 	/// ```
@@ -109,11 +248,9 @@ pub trait Treelike: Sized + Copy {
 	/// node.callback_dft(
 	/// 	|content, depth| {
 	/// 		dbg!((content, depth));
-	/// 		},
-	/// 	(|content, depth, child| **content != 4 && depth <= 1)
-	/// #   //FIXME: I do not understand why this cast is needed
-	///     as for<'r, 's> fn(&'r &usize, usize, &'s LinTree<'_, usize>) -> _,
-	/// 	)
+	/// 	},
+	/// 	node.child_filter(|content, depth, _child| **content != 4 && depth <= 1),
+	/// )
 	/// ```
 	///
 	/// # no_std note
@@ -137,6 +274,160 @@ pub trait Treelike: Sized + Copy {
 		callback_dft_pre(self, callback, child_filter, 0);
 	}
 
+	/// Like [callback_dft][Treelike::callback_dft] but the callback gets a [NodeContext] with the
+	/// position of the node among its siblings instead of just the depth.
+	///
+	/// The filter is built through [build_ctx][FilterBuilder::build_ctx].
+	///
+	/// # no_std note
+	/// Like [callback_dft][Treelike::callback_dft] this uses the call-stack.
+	fn callback_dft_ctx<CB: FnMut(Self::Content, NodeContext), F: FilterBuilder<Self>>(
+		self,
+		callback: CB,
+		child_filter: F,
+	) {
+		context::callback_dft_ctx(self, callback, child_filter, NodeContext::root(), false);
+	}
+
+	/// Like [callback_dft_ctx][Treelike::callback_dft_ctx] but pre-order.
+	///
+	/// Drawing a tree:
+	/// ```
+	/// # use treelike::example::LinTree;
+	/// # use treelike::Treelike;
+	/// let base = [0, 1, 2, 3, 4, 5];
+	/// let node = LinTree::new(0, &base);
+	///
+	/// let mut drawing = String::new();
+	/// // whether the node at each depth on the way here was the last of its siblings
+	/// let mut last = Vec::new();
+	/// node.callback_dft_pre_ctx(
+	/// 	|content, ctx| {
+	/// 		last.truncate(ctx.depth);
+	/// 		for &parent in last.iter().skip(1) {
+	/// 			drawing += if parent { "    " } else { "│   " };
+	/// 		}
+	/// 		if ctx.depth > 0 {
+	/// 			drawing += if ctx.is_last {
+	/// 				"└── "
+	/// 			} else {
+	/// 				"├── "
+	/// 			};
+	/// 		}
+	/// 		drawing += &format!("{}\n", content);
+	/// 		last.push(ctx.is_last);
+	/// 	},
+	/// 	(),
+	/// );
+	/// assert_eq!(
+	/// 	drawing,
+	/// 	"0\n├── 1\n│   ├── 3\n│   └── 4\n└── 2\n    └── 5\n"
+	/// );
+	/// ```
+	fn callback_dft_pre_ctx<CB: FnMut(Self::Content, NodeContext), F: FilterBuilder<Self>>(
+		self,
+		callback: CB,
+		child_filter: F,
+	) {
+		context::callback_dft_ctx(self, callback, child_filter, NodeContext::root(), true);
+	}
+
+	/// Traverses the tree depth first, calling callback with an [Event::Enter] before and an
+	/// [Event::Exit] after visiting a nodes children.
+	///
+	/// ```
+	/// # use treelike::example::LinTree;
+	/// # use treelike::Treelike;
+	/// use treelike::events::Event;
+	///
+	/// let base = ["html", "head", "body"];
+	/// let node = LinTree::new(0, &base);
+	///
+	/// let mut xml = String::new();
+	/// node.callback_events(
+	/// 	|event| match event {
+	/// 		Event::Enter(tag, _) => xml += &format!("<{}>", tag),
+	/// 		Event::Exit(tag, _) => xml += &format!("</{}>", tag),
+	/// 	},
+	/// 	(),
+	/// );
+	/// assert_eq!(xml, "<html><head></head><body></body></html>");
+	/// ```
+	///
+	/// # no_std note
+	/// Like [callback_dft][Treelike::callback_dft] this uses the call-stack.
+	fn callback_events<CB: FnMut(Event<Self::Content>), F: FilterBuilder<Self>>(
+		self,
+		callback: CB,
+		child_filter: F,
+	) {
+		events::callback_events(self, callback, child_filter, 0);
+	}
+
+	/// Visits the contents of all leaves, i.e. nodes without (unfiltered) children, in
+	/// depth-first pre-order.
+	///
+	/// ```
+	/// # use treelike::example::LinTree;
+	/// # use treelike::Treelike;
+	/// let base = [0, 1, 2, 3, 4];
+	/// let node = LinTree::new(0, &base);
+	///
+	/// let mut leaves = Vec::new();
+	/// node.callback_leaves(|content, _depth| leaves.push(*content), ());
+	/// assert_eq!(leaves, [3, 4, 2]);
+	/// ```
+	///
+	/// # no_std note
+	/// Like [callback_dft][Treelike::callback_dft] this uses the call-stack.
+	fn callback_leaves<CB: FnMut(Self::Content, usize), F: FilterBuilder<Self>>(
+		self,
+		callback: CB,
+		child_filter: F,
+	) {
+		parts::callback_leaves(self, callback, child_filter, 0, true);
+	}
+
+	/// Visits the contents of all nodes that have (unfiltered) children, in depth-first
+	/// pre-order.
+	fn callback_internal_nodes<CB: FnMut(Self::Content, usize), F: FilterBuilder<Self>>(
+		self,
+		callback: CB,
+		child_filter: F,
+	) {
+		parts::callback_leaves(self, callback, child_filter, 0, false);
+	}
+
+	/// Visits every edge as the contents of the parent and the child, together with the depth
+	/// of the child, in depth-first pre-order.
+	fn callback_edges<CB: FnMut(Self::Content, Self::Content, usize), F: FilterBuilder<Self>>(
+		self,
+		callback: CB,
+		child_filter: F,
+	) {
+		parts::callback_edges(self, callback, child_filter, 0);
+	}
+
+	/// Calls callback with the contents of all nodes on the way from this node to each leaf.
+	#[cfg(feature = "alloc")]
+	fn callback_root_to_leaf_paths<CB: FnMut(&[Self::Content]), F: FilterBuilder<Self>>(
+		self,
+		callback: CB,
+		child_filter: F,
+	) {
+		parts::callback_paths(self, callback, child_filter, &mut Vec::new());
+	}
+
+	/// Runs a [TreeVisitor] over the tree, depth first.
+	///
+	/// Returns false if the visitor stopped the traversal early.
+	///
+	/// # no_std note
+	/// Like [callback_dft][Treelike::callback_dft] this uses the call-stack.
+	fn visit<V: TreeVisitor<Self> + ?Sized>(self, visitor: &mut V) -> bool {
+		visitor::visit(self, visitor, 0)
+	}
+
 	/// Traverses the tree breadth-first, i.e. one depth-layer at a time.
 	/// # Example
 	/// ```
@@ -168,6 +459,9 @@ pub trait Treelike: Sized + Copy {
 
 	/// Like [callback_bft][Treelike::callback_bft] but allows filtering, thereby disallowing some
 	/// optimizations.
+	///
+	/// Like `iter_iddfs` this is an iterative deepening depth-first search.
+	/// Pass a [MaxDepth] to stop early on huge trees.
 	fn callback_bft_filtered<CB: FnMut(Self::Content, usize), F: FilterBuilder<Self>>(
 		self,
 		mut callback: CB,
@@ -177,8 +471,8 @@ pub trait Treelike: Sized + Copy {
 		let mut count = 0;
 
 		loop {
-			callback_bft(
-				self,
+			let _ = callback_bft(
+				self.clone(),
 				|content| {
 					count += 1;
 					callback(content, depth)
@@ -195,6 +489,34 @@ pub trait Treelike: Sized + Copy {
 		}
 	}
 
+	/// Visits the contents of all nodes at exactly depth below this node, left to right.
+	///
+	/// # Performance note
+	/// The default implementation has to traverse all nodes above depth.
+	/// [LinTree][crate::example::LinTree] overrides this with a slice iteration, see
+	/// [level_slice][crate::example::LinTree::level_slice].
+	fn callback_level<CB: FnMut(Self::Content)>(self, depth: usize, callback: CB) {
+		let _ = callback_bft(self, callback, (), depth, 0);
+	}
+
+	/// Returns the contents of all nodes at exactly depth below this node, left to right.
+	///
+	/// ```
+	/// # use treelike::example::LinTree;
+	/// # use treelike::Treelike;
+	/// let base = [0, 1, 2, 3, 4, 5];
+	/// let node = LinTree::new(0, &base);
+	///
+	/// assert_eq!(node.level(2), [&3, &4, &5]);
+	/// assert!(node.level(3).is_empty());
+	/// ```
+	#[cfg(feature = "alloc")]
+	fn level(self, depth: usize) -> Vec<Self::Content> {
+		let mut level = Vec::new();
+		self.callback_level(depth, |content| level.push(content));
+		level
+	}
+
 	//TODO: how do I build in-order traversals for trees with more then 2 children? maybe first
 	//child, content, other children
 
@@ -211,10 +533,186 @@ pub trait Treelike: Sized + Copy {
 		self,
 		filter: F,
 	) -> Chain<Once<Self::Content>, BFT<Self, F>> {
-		once(self.content()).chain(BFT::new(self, filter))
+		once(self.clone().content()).chain(BFT::new(self, filter))
+	}
+
+	/// Like [iter_bft][Treelike::iter_bft] but yields all contents of one depth-layer at a
+	/// time, starting with a layer containing only this node.
+	///
+	/// ```
+	/// # use treelike::example::LinTree;
+	/// # use treelike::Treelike;
+	/// let base = [0, 1, 2, 3, 4, 5];
+	/// let node = LinTree::new(0, &base);
+	///
+	/// let widths: Vec<usize> = node.iter_levels(()).map(|level| level.len()).collect();
+	/// assert_eq!(widths, [1, 2, 3]);
+	/// ```
+	#[cfg(feature = "alloc")]
+	fn iter_levels<F: FilterBuilder<Self>>(self, filter: F) -> Levels<Self, F> {
+		Levels::new(self, filter)
+	}
+
+	/// Visits nodes in the same order as [iter_bft][Treelike::iter_bft] but only needs memory
+	/// proportional to the depth of the tree instead of its width.
+	///
+	/// This is done by repeatedly traversing the tree depth-first, each time one level deeper,
+	/// so nodes near the root are visited many times. This pays off for huge or generated trees
+	/// where a breadth-first queue would not fit into memory.
+	///
+	/// ```
+	/// # use treelike::example::LinTree;
+	/// # use treelike::Treelike;
+	/// use treelike::treelike::MaxDepth;
+	///
+	/// let base = [0, 1, 2, 3, 4, 5, 6];
+	/// let node = LinTree::new(0, &base);
+	///
+	/// let order: Vec<_> = node.iter_iddfs(MaxDepth::new(1)).collect();
+	/// assert_eq!(order, [&0, &1, &2]);
+	/// ```
+	#[cfg(feature = "alloc")]
+	fn iter_iddfs<F: FilterBuilder<Self>>(self, filter: F) -> IDDFS<Self, F> {
+		IDDFS::new(self, filter)
+	}
+
+	/// Like [callback_dft][Treelike::callback_dft] but visits children right to left.
+	///
+	/// All `*_rev` methods are shortcuts for passing the filter wrapped in [Reversed], which
+	/// works for every method taking a filter.
+	fn callback_dft_rev<CB: FnMut(Self::Content, usize), F: FilterBuilder<Self>>(
+		self,
+		callback: CB,
+		child_filter: F,
+	) where
+		F::Filter: DoubleEndedIterator,
+	{
+		self.callback_dft(callback, Reversed(child_filter))
+	}
+
+	/// Like [callback_dft_pre][Treelike::callback_dft_pre] but visits children right to left.
+	fn callback_dft_pre_rev<CB: FnMut(Self::Content, usize), F: FilterBuilder<Self>>(
+		self,
+		callback: CB,
+		child_filter: F,
+	) where
+		F::Filter: DoubleEndedIterator,
+	{
+		self.callback_dft_pre(callback, Reversed(child_filter))
+	}
+
+	/// Like [callback_bft][Treelike::callback_bft] but visits each layer right to left.
+	fn callback_bft_rev<CB: FnMut(Self::Content, usize)>(self, callback: CB)
+	where
+		Self::ChildIterator: DoubleEndedIterator,
+	{
+		self.callback_bft_filtered(callback, Reversed(()))
+	}
+
+	/// Like [iter_dft][Treelike::iter_dft] but visits children right to left.
+	///
+	/// Note that this is not the same as `iter_dft(filter).rev()`, which is a mirrored
+	/// pre-order traversal.
+	///
+	/// ```
+	/// # use treelike::example::LinTree;
+	/// # use treelike::Treelike;
+	/// let base = [0, 1, 2, 3, 4];
+	/// let node = LinTree::new(0, &base);
+	///
+	/// let post: Vec<_> = node.iter_dft_rev(()).collect();
+	/// assert_eq!(post, [&2, &4, &3, &1, &0]);
+	/// let reverse_pre: Vec<_> = node.iter_dft_pre(()).rev().collect();
+	/// assert_eq!(post, reverse_pre);
+	/// ```
+	#[cfg(feature = "alloc")]
+	fn iter_dft_rev<F: FilterBuilder<Self>>(self, filter: F) -> DFT<Self, Reversed<F>>
+	where
+		F::Filter: DoubleEndedIterator,
+	{
+		self.iter_dft(Reversed(filter))
+	}
+
+	/// Like [iter_dft_pre][Treelike::iter_dft_pre] but visits children right to left.
+	#[cfg(feature = "alloc")]
+	fn iter_dft_pre_rev<F: FilterBuilder<Self>>(self, filter: F) -> DFTP<Self, Reversed<F>>
+	where
+		F::Filter: DoubleEndedIterator,
+	{
+		self.iter_dft_pre(Reversed(filter))
+	}
+
+	/// Like [iter_bft][Treelike::iter_bft] but visits each layer right to left.
+	#[cfg(feature = "alloc")]
+	fn iter_bft_rev<F: FilterBuilder<Self>>(
+		self,
+		filter: F,
+	) -> Chain<Once<Self::Content>, BFT<Self, Reversed<F>>>
+	where
+		F::Filter: DoubleEndedIterator,
+	{
+		self.iter_bft(Reversed(filter))
+	}
+
+	#[cfg(feature = "alloc")]
+	fn iter_events<F: FilterBuilder<Self>>(self, filter: F) -> Events<Self, F> {
+		Events::new(self, filter)
+	}
+
+	/// Iterator version of [callback_leaves][Treelike::callback_leaves].
+	#[cfg(feature = "alloc")]
+	fn iter_leaves<F: FilterBuilder<Self>>(self, filter: F) -> Leaves<Self, F> {
+		Leaves::new(self, filter)
+	}
+
+	/// Iterator version of [callback_internal_nodes][Treelike::callback_internal_nodes].
+	#[cfg(feature = "alloc")]
+	fn iter_internal_nodes<F: FilterBuilder<Self>>(self, filter: F) -> InternalNodes<Self, F> {
+		InternalNodes::new(self, filter)
+	}
+
+	/// Iterator version of [callback_edges][Treelike::callback_edges], yielding
+	/// `(parent, child)` pairs.
+	#[cfg(feature = "alloc")]
+	fn iter_edges<F: FilterBuilder<Self>>(self, filter: F) -> Edges<Self, F> {
+		Edges::new(self, filter)
+	}
+
+	/// Iterator version of
+	/// [callback_root_to_leaf_paths][Treelike::callback_root_to_leaf_paths].
+	#[cfg(feature = "alloc")]
+	fn iter_root_to_leaf_paths<F: FilterBuilder<Self>>(
+		self,
+		filter: F,
+	) -> RootToLeafPaths<Self, F> {
+		RootToLeafPaths::new(self, filter)
+	}
+
+	/// Like [iter_dft_pre][Treelike::iter_dft_pre], but also yields the [TreePath] of each node
+	/// relative to this one.
+	///
+	/// This can not be filtered, as filters hide the original index of the children.
+	#[cfg(feature = "alloc")]
+	fn iter_dft_pre_paths(self) -> PathDFTP<Self> { PathDFTP::new(self) }
+}
+#[cfg(feature = "alloc")]
+use core::iter::{Chain, Once, once};
+use core::iter::{Flatten, Rev};
+
+#[cfg(feature = "alloc")]
+fn path_to<T: Treelike + PartialEq>(t: T, target: T, path: &mut TreePath) -> bool {
+	if t == target {
+		return true;
+	}
+	for (index, child) in t.children().enumerate() {
+		path.push(index);
+		if path_to(child, target.clone(), path) {
+			return true;
+		}
+		path.pop();
 	}
+	false
 }
-use core::iter::{once, Chain, Once};
 
 fn callback_dft<T: Treelike, CB: FnMut(T::Content, usize), F: FilterBuilder<T>>(
 	t: T,
@@ -222,7 +720,7 @@ fn callback_dft<T: Treelike, CB: FnMut(T::Content, usize), F: FilterBuilder<T>>(
 	f: F,
 	depth: usize,
 ) -> CB {
-	let filter = f.build(t.content(), depth, t.children());
+	let filter = f.build(t.clone().content(), depth, t.clone().children());
 	for child in filter {
 		cb = callback_dft(child, cb, f, depth + 1)
 	}
@@ -237,9 +735,9 @@ fn callback_dft_pre<T: Treelike, CB: FnMut(T::Content, usize), F: FilterBuilder<
 	f: F,
 	depth: usize,
 ) -> CB {
-	cb(t.content(), depth);
+	cb(t.clone().content(), depth);
 
-	let filter = f.build(t.content(), depth, t.children());
+	let filter = f.build(t.clone().content(), depth, t.children());
 	for child in filter {
 		cb = callback_dft_pre(child, cb, f, depth + 1)
 	}
@@ -247,7 +745,7 @@ fn callback_dft_pre<T: Treelike, CB: FnMut(T::Content, usize), F: FilterBuilder<
 	cb
 }
 
-fn callback_bft<T: Treelike, CB: FnMut(T::Content), F: FilterBuilder<T>>(
+pub(crate) fn callback_bft<T: Treelike, CB: FnMut(T::Content), F: FilterBuilder<T>>(
 	t: T,
 	mut callback: CB,
 	f: F,
@@ -259,7 +757,7 @@ fn callback_bft<T: Treelike, CB: FnMut(T::Content), F: FilterBuilder<T>>(
 		return callback;
 	}
 
-	for child in t.children() {
+	for child in f.build(t.clone().content(), depth, t.children()) {
 		callback = callback_bft(child, callback, f, limit, depth + 1)
 	}
 
@@ -269,6 +767,17 @@ fn callback_bft<T: Treelike, CB: FnMut(T::Content), F: FilterBuilder<T>>(
 pub trait FilterBuilder<T: Treelike>: Copy {
 	type Filter: Iterator<Item = T>;
 	fn build(self, content: T::Content, depth: usize, children: T::ChildIterator) -> Self::Filter;
+
+	/// Like [build][FilterBuilder::build] but with the position of the node among its siblings.
+	/// Called by the `*_ctx` traversals, falls back to [build][FilterBuilder::build].
+	fn build_ctx(
+		self,
+		content: T::Content,
+		context: NodeContext,
+		children: T::ChildIterator,
+	) -> Self::Filter {
+		self.build(content, context.depth, children)
+	}
 }
 
 impl<T: Treelike> FilterBuilder<T> for () {
@@ -290,15 +799,22 @@ impl<T: Treelike, F: Fn(&T::Content, usize, &T) -> bool, I: Iterator<Item = T>>
 	type Item = T;
 	fn next(&mut self) -> Option<T> {
 		// this is basically just filter but with context
-		self.inner_iter
-			.next()
-			.filter(|child| (self.inner_filter)(&self.content, self.depth, &child))
+		let (content, depth, filter) = (&self.content, self.depth, &self.inner_filter);
+		self.inner_iter.find(|child| filter(content, depth, child))
 	}
 }
 
-//FIXME: this should not contain anonymous lifetimes because that forces casts down the line.
-//but if I try to introduce lifetimes I get "unconstrained lifetime parameter (E0207)" even though
-//the lifetime is clearly used in F...
+impl<T: Treelike, F: Fn(&T::Content, usize, &T) -> bool, I: DoubleEndedIterator<Item = T>>
+	DoubleEndedIterator for PseudoCurry<T, F, I>
+{
+	fn next_back(&mut self) -> Option<T> {
+		let (content, depth, filter) = (&self.content, self.depth, &self.inner_filter);
+		self.inner_iter.rfind(|child| filter(content, depth, child))
+	}
+}
+
+// closures passed directly do not get higher-ranked arguments, so they only match this with a cast
+// to a fn pointer. Treelike::child_filter wraps them into a ChildFilter instead.
 impl<T: Treelike, F: Copy + Fn(&T::Content, usize, &T) -> bool> FilterBuilder<T> for F {
 	type Filter = PseudoCurry<T, F, T::ChildIterator>;
 
@@ -312,43 +828,329 @@ impl<T: Treelike, F: Copy + Fn(&T::Content, usize, &T) -> bool> FilterBuilder<T>
 	}
 }
 
-#[derive(Clone, Copy)]
-pub struct M<T: Clone + Copy>(T);
+/// Filter that stops traversals at a given depth, the root being at depth 0.
+///
+/// It can wrap another filter, which is then applied to all nodes above the limit.
+///
+/// ```
+/// # use treelike::example::LinTree;
+/// # use treelike::Treelike;
+/// use treelike::treelike::MaxDepth;
+///
+/// let base = [0, 1, 2, 3, 4, 5, 6];
+/// let node = LinTree::new(0, &base);
+///
+/// let mut visited = Vec::new();
+/// node.callback_dft_pre(|content, _depth| visited.push(*content), MaxDepth::new(1));
+/// assert_eq!(visited, [0, 1, 2]);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct MaxDepth<F = ()> {
+	filter: F,
+	max: usize,
+}
+
+impl MaxDepth {
+	pub fn new(max: usize) -> Self { MaxDepth { filter: (), max } }
+}
+
+impl<F> MaxDepth<F> {
+	/// Limits the depth of a traversal that is already filtered by filter.
+	pub fn with(filter: F, max: usize) -> Self { MaxDepth { filter, max } }
+}
+
+impl<T: Treelike, F: FilterBuilder<T>> FilterBuilder<T> for MaxDepth<F> {
+	type Filter = Flatten<core::option::IntoIter<F::Filter>>;
+
+	fn build(self, content: T::Content, depth: usize, children: T::ChildIterator) -> Self::Filter {
+		let filtered = self.filter.build(content, depth, children);
+		Some(filtered)
+			.filter(|_| depth < self.max)
+			.into_iter()
+			.flatten()
+	}
+
+	fn build_ctx(
+		self,
+		content: T::Content,
+		context: NodeContext,
+		children: T::ChildIterator,
+	) -> Self::Filter {
+		let filtered = self.filter.build_ctx(content, context, children);
+		Some(filtered)
+			.filter(|_| context.depth < self.max)
+			.into_iter()
+			.flatten()
+	}
+}
+
+/// Filter that visits children right to left, mirroring the traversal.
+///
+/// Wraps another filter, for a pure traversal pass `Reversed(())`.
+/// This requires the wrapped filter to be a [DoubleEndedIterator], which is the case for `()` if
+/// the trees [ChildIterator][Treelike::ChildIterator] is one.
+///
+/// The `*_rev` methods on [Treelike] are shortcuts for this.
+#[derive(Clone, Copy, Debug)]
+pub struct Reversed<F>(pub F);
+
+impl<T: Treelike, F: FilterBuilder<T>> FilterBuilder<T> for Reversed<F>
+where
+	F::Filter: DoubleEndedIterator,
+{
+	type Filter = Rev<F::Filter>;
+
+	fn build(self, content: T::Content, depth: usize, children: T::ChildIterator) -> Self::Filter {
+		self.0.build(content, depth, children).rev()
+	}
+
+	fn build_ctx(
+		self,
+		content: T::Content,
+		context: NodeContext,
+		children: T::ChildIterator,
+	) -> Self::Filter {
+		self.0.build_ctx(content, context, children).rev()
+	}
+}
+
+/// Filter deciding for each child whether to visit it,
+/// usually built by [child_filter][Treelike::child_filter].
+#[derive(Clone, Copy, Debug)]
+pub struct ChildFilter<P>(pub P);
+
+impl<T: Treelike, P: Fn(&T::Content, usize, &T) -> bool + Copy> FilterBuilder<T>
+	for ChildFilter<P>
+{
+	type Filter = PseudoCurry<T, P, T::ChildIterator>;
+
+	fn build(self, content: T::Content, depth: usize, children: T::ChildIterator) -> Self::Filter {
+		PseudoCurry {
+			content,
+			depth,
+			inner_filter: self.0,
+			inner_iter: children,
+		}
+	}
+}
+
+/// Filter deciding for each child whether to visit it, knowing the [NodeContext] of the node,
+/// usually built by [child_filter_ctx][Treelike::child_filter_ctx].
+#[derive(Clone, Copy, Debug)]
+pub struct ChildFilterCtx<P>(pub P);
+
+impl<T: Treelike, P: Fn(&T::Content, NodeContext, &T) -> bool + Copy> FilterBuilder<T>
+	for ChildFilterCtx<P>
+{
+	type Filter = ContextCurry<T, P, T::ChildIterator>;
+
+	fn build(self, content: T::Content, depth: usize, children: T::ChildIterator) -> Self::Filter {
+		let context = NodeContext {
+			depth,
+			..NodeContext::root()
+		};
+		self.build_ctx(content, context, children)
+	}
+
+	fn build_ctx(
+		self,
+		content: T::Content,
+		context: NodeContext,
+		children: T::ChildIterator,
+	) -> Self::Filter {
+		ContextCurry {
+			content,
+			context,
+			inner_filter: self.0,
+			inner_iter: children,
+		}
+	}
+}
+
+/// The children that pass a [ChildFilterCtx].
+pub struct ContextCurry<T: Treelike, P, I> {
+	content: T::Content,
+	context: NodeContext,
+	inner_filter: P,
+	inner_iter: I,
+}
+
+impl<T: Treelike, P: Fn(&T::Content, NodeContext, &T) -> bool, I: Iterator<Item = T>> Iterator
+	for ContextCurry<T, P, I>
+{
+	type Item = T;
+	fn next(&mut self) -> Option<T> {
+		let (content, context, filter) = (&self.content, self.context, &self.inner_filter);
+		self.inner_iter
+			.find(|child| filter(content, context, child))
+	}
+}
+
+impl<T: Treelike, P: Fn(&T::Content, NodeContext, &T) -> bool, I: DoubleEndedIterator<Item = T>>
+	DoubleEndedIterator for ContextCurry<T, P, I>
+{
+	fn next_back(&mut self) -> Option<T> {
+		let (content, context, filter) = (&self.content, self.context, &self.inner_filter);
+		self.inner_iter
+			.rfind(|child| filter(content, context, child))
+	}
+}
+
+/// Filter replacing the children of each node with whatever iterator the closure returns,
+/// usually built by [replace_children][Treelike::replace_children].
 // any kind of Fn trait, even with incompatible arguments might be implemented on a single type
 // you can't have multiple implementations of a trait for multiple Fn-traits.
 // so we need to newtype-wrap it..
-//FIXME: https://github.com/rust-lang/rust/issues/60074
-impl<
-		T: Treelike,
-		I: Iterator<Item = T>,
-		F: Fn(&T::Content, usize, T::ChildIterator) -> I + Copy,
-	> FilterBuilder<T> for M<F>
+#[derive(Clone, Copy, Debug)]
+pub struct ReplaceChildren<R>(pub R);
+
+// the content is passed by value, a reference could not end up in the returned iterator
+impl<T: Treelike, I: Iterator<Item = T>, R: Fn(T::Content, usize, T::ChildIterator) -> I + Copy>
+	FilterBuilder<T> for ReplaceChildren<R>
 {
 	type Filter = I;
 
 	fn build(self, content: T::Content, depth: usize, children: T::ChildIterator) -> Self::Filter {
-		(self.0)(&content, depth, children)
+		(self.0)(content, depth, children)
 	}
 }
 
+/// Keeps track of how many items are left when an iterator is consumed from both ends.
+///
+/// The depth-first iterators can not know when the front and the back meet without knowing the
+/// total amount of nodes, so that is counted once the back is first used.
+#[cfg(feature = "alloc")]
+#[derive(Default)]
+struct Meet {
+	/// amount of items yielded from the front before the back was first used
+	yielded: usize,
+	remaining: Option<usize>,
+}
+
+#[cfg(feature = "alloc")]
+impl Meet {
+	fn done(&self) -> bool { self.remaining == Some(0) }
+
+	fn record<X>(&mut self, item: Option<X>) -> Option<X> {
+		if item.is_some() {
+			match &mut self.remaining {
+				Some(remaining) => *remaining -= 1,
+				None => self.yielded += 1,
+			}
+		}
+		item
+	}
+
+	fn start(&mut self, total: impl FnOnce() -> usize) {
+		if self.remaining.is_none() {
+			self.remaining = Some(total() - self.yielded);
+		}
+	}
+}
+
+#[cfg(feature = "alloc")]
+fn count<T: Treelike, F: FilterBuilder<T>>(t: T, f: F, depth: usize) -> usize {
+	let mut count = 0;
+	let _ = callback_dft(t, |_, _| count += 1, f, depth);
+	count
+}
+
+#[cfg(feature = "alloc")]
+fn count_children<T: Treelike, I: Iterator<Item = T>, F: FilterBuilder<T>>(
+	children: I,
+	f: F,
+	depth: usize,
+) -> usize {
+	children.map(|child| count(child, f, depth)).sum()
+}
+
+/// Iterator that remembers how many items it returned.
+#[cfg(feature = "alloc")]
+struct Taken<I> {
+	inner: I,
+	taken: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator> Iterator for Taken<I> {
+	type Item = I::Item;
+
+	fn next(&mut self) -> Option<I::Item> {
+		let item = self.inner.next();
+		self.taken += item.is_some() as usize;
+		item
+	}
+}
+
+/// One step of a post-order traversal, returns the next finished node.
+#[cfg(feature = "alloc")]
+fn post_next<T: Treelike, I: Iterator<Item = T>>(
+	stack: &mut Vec<(T, I)>,
+	mut build: impl FnMut(T, usize) -> I,
+) -> Option<T> {
+	loop {
+		let (_, children) = stack.last_mut()?;
+		// if we still have children left to visit, visit those first
+		if let Some(child) = children.next() {
+			let filtered = build(child.clone(), stack.len());
+			stack.push((child, filtered));
+		} else {
+			// else this node is done
+			return stack.pop().map(|(node, _)| node);
+		}
+	}
+}
+
+/// One step of a pre-order traversal, returns the next node after pushing its children.
+#[cfg(feature = "alloc")]
+fn pre_next<T: Treelike, I: Iterator<Item = T>>(
+	stack: &mut Vec<I>,
+	mut build: impl FnMut(T, usize) -> I,
+) -> Option<T> {
+	loop {
+		let children = stack.last_mut()?;
+		if let Some(child) = children.next() {
+			// children is not empty yeet, keep it and push child for next iteration
+			let filtered = build(child.clone(), stack.len());
+			stack.push(filtered);
+			return Some(child);
+		}
+		stack.pop();
+	}
+}
+
+/// Depth-first post-order iterator, see [iter_dft][Treelike::iter_dft].
+///
+/// If the filter is a [DoubleEndedIterator] so is this, iterating from the back is a mirrored
+/// pre-order traversal. The first call to [next_back][DoubleEndedIterator::next_back] traverses
+/// the whole tree once to count its nodes.
 #[cfg(feature = "alloc")]
 pub struct DFT<T: Treelike, F: FilterBuilder<T>> {
 	stack: Vec<(T, F::Filter)>,
 	filter: F,
+	root: T,
+	back: Vec<Rev<F::Filter>>,
+	meet: Meet,
 }
 
 #[cfg(feature = "alloc")]
 impl<T: Treelike, F: FilterBuilder<T>> DFT<T, F> {
 	fn new(treelike: T, filter: F) -> Self {
 		let stack = Vec::new();
-		let mut s = Self { stack, filter };
+		let mut s = Self {
+			stack,
+			filter,
+			root: treelike.clone(),
+			back: Vec::new(),
+			meet: Meet::default(),
+		};
 		s.push(treelike);
 		s
 	}
 	fn push(&mut self, t: T) {
-		let filtered = self
-			.filter
-			.build(t.content(), self.stack.len(), t.children());
+		let filtered =
+			self.filter
+				.build(t.clone().content(), self.stack.len(), t.clone().children());
 		self.stack.push((t, filtered));
 	}
 }
@@ -357,25 +1159,61 @@ impl<T: Treelike, F: FilterBuilder<T>> DFT<T, F> {
 impl<T: Treelike, F: FilterBuilder<T>> Iterator for DFT<T, F> {
 	type Item = T::Content;
 	fn next(&mut self) -> Option<Self::Item> {
-		let (node, mut children) = self.stack.pop()?;
-		// if we still have children left to visit, visit those first
-		if let Some(child) = children.next() {
-			self.stack.push((node, children));
-			self.push(child);
-			self.next()
-		} else {
-			// else this node is done and we return the content.
-			Some(node.content())
+		if self.meet.done() {
+			return None;
 		}
+		let filter = self.filter;
+		let node = post_next(&mut self.stack, |t, depth| {
+			filter.build(t.clone().content(), depth, t.children())
+		});
+		self.meet.record(node).map(T::content)
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Treelike, F: FilterBuilder<T>> DoubleEndedIterator for DFT<T, F>
+where
+	F::Filter: DoubleEndedIterator,
+{
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let (root, filter) = (self.root.clone(), self.filter);
+		let build = |t: T, depth| filter.build(t.clone().content(), depth, t.children()).rev();
+		let node = if self.meet.remaining.is_none() {
+			self.meet.start(|| count(root.clone(), filter, 0));
+			if self.meet.done() {
+				return None;
+			}
+			self.back.push(build(root.clone(), 0));
+			Some(root)
+		} else if self.meet.done() {
+			return None;
+		} else {
+			pre_next(&mut self.back, build)
+		};
+		self.meet.record(node).map(T::content)
 	}
 }
 
 //FIXME: test these implementations and add methods on Treelike
+/// Depth-first pre-order iterator, see [iter_dft_pre][Treelike::iter_dft_pre].
+///
+/// Unlike the other iterators this one can be steered while iterating, see
+/// [skip_subtree][DFTP::skip_subtree].
+///
+/// If the filter is a [DoubleEndedIterator] so is this, iterating from the back is a mirrored
+/// post-order traversal. The first call to [next_back][DoubleEndedIterator::next_back] traverses
+/// the whole tree once to count its nodes.
 #[cfg(feature = "alloc")]
 pub struct DFTP<T: Treelike, F: FilterBuilder<T>> {
-	stack: Vec<F::Filter>,
+	stack: Vec<Taken<F::Filter>>,
 	filter: F,
 	cur: Option<T::Content>,
+	depth: Option<usize>,
+	/// true if the children of the last returned node are on top of the stack
+	skippable: bool,
+	root: T,
+	back: Vec<(T, Rev<F::Filter>)>,
+	meet: Meet,
 }
 
 #[cfg(feature = "alloc")]
@@ -386,33 +1224,138 @@ impl<T: Treelike, F: FilterBuilder<T>> DFTP<T, F> {
 			stack,
 			filter,
 			cur: None,
+			depth: None,
+			skippable: false,
+			root: treelike.clone(),
+			back: Vec::new(),
+			meet: Meet::default(),
 		};
 		s.push(treelike);
 		s
 	}
 	fn push(&mut self, t: T) {
-		let filtered = self
-			.filter
-			.build(t.content(), self.stack.len(), t.children());
-		self.stack.push(filtered);
+		let filtered =
+			self.filter
+				.build(t.clone().content(), self.stack.len(), t.clone().children());
+		self.stack.push(Taken {
+			inner: filtered,
+			taken: 0,
+		});
 		self.cur = Some(t.content());
 	}
+
+	/// Amount of nodes the front returned or skipped, found by retracing its path from the root.
+	fn consumed(&self) -> usize {
+		let filter = self.filter;
+		if self.cur.is_some() {
+			return 0;
+		}
+		if self.stack.is_empty() {
+			return count(self.root.clone(), filter, 0);
+		}
+		let mut node = self.root.clone();
+		let mut consumed = 1;
+		for (depth, level) in self.stack.iter().enumerate() {
+			let mut children = filter.build(node.clone().content(), depth, node.children());
+			// all but the last taken child are done unless the stack ends here
+			let on_path = depth + 1 < self.stack.len();
+			let done = level.taken - on_path as usize;
+			consumed += count_children((&mut children).take(done), filter, depth + 1);
+			match children.next() {
+				Some(child) if on_path => node = child,
+				_ => break,
+			}
+			consumed += 1;
+		}
+		consumed
+	}
+
+	/// Don't visit the children of the node that was returned last.
+	///
+	/// Like walkdirs `skip_current_dir` this allows pruning based on state gathered during the
+	/// iteration, which a filter can not do. Calling this before the first or repeatedly after
+	/// the same call to [next][Iterator::next] does nothing.
+	///
+	/// ```
+	/// # use treelike::example::LinTree;
+	/// # use treelike::Treelike;
+	/// let base = [0, 1, 2, 3, 4, 5, 6];
+	/// let mut iter = LinTree::new(0, &base).iter_dft_pre(());
+	///
+	/// let mut visited = Vec::new();
+	/// while let Some(content) = iter.next() {
+	/// 	visited.push(*content);
+	/// 	if *content == 1 {
+	/// 		iter.skip_subtree();
+	/// 	}
+	/// }
+	/// assert_eq!(visited, [0, 1, 2, 5, 6]);
+	/// ```
+	pub fn skip_subtree(&mut self) {
+		if !core::mem::take(&mut self.skippable) {
+			return;
+		}
+		let children = self.stack.pop();
+		// the skipped nodes directly follow the last returned one, so if the back already
+		// returned some of them only the ones before that are still remaining.
+		// if the back is not used yet they are accounted for once it is.
+		if let (Some(children), Some(remaining)) = (children, self.meet.remaining.as_mut()) {
+			let depth = self.stack.len() + 1;
+			let skipped = count_children(children, self.filter, depth);
+			*remaining -= skipped.min(*remaining);
+		}
+	}
+
+	/// The depth of the node that was returned last, [None] before the first call to
+	/// [next][Iterator::next].
+	pub fn depth(&self) -> Option<usize> { self.depth }
 }
 
 #[cfg(feature = "alloc")]
 impl<T: Treelike, F: FilterBuilder<T>> Iterator for DFTP<T, F> {
 	type Item = T::Content;
 	fn next(&mut self) -> Option<Self::Item> {
-		self.cur.take().or_else(|| {
-			let mut children = self.stack.pop()?;
-			if let Some(child) = children.next() {
-				// children is not empty yeet, put it back and push child for next
-				// iteration
-				self.stack.push(children);
-				self.push(child);
-			}
-			self.next()
-		})
+		self.skippable = false;
+		if self.meet.done() {
+			return None;
+		}
+		let content = if let Some(cur) = self.cur.take() {
+			Some(cur)
+		} else {
+			let filter = self.filter;
+			pre_next(&mut self.stack, |t, depth| Taken {
+				inner: filter.build(t.clone().content(), depth, t.children()),
+				taken: 0,
+			})
+			.map(T::content)
+		};
+		if content.is_some() {
+			// the children of the returned node have just been pushed
+			self.depth = Some(self.stack.len() - 1);
+			self.skippable = true;
+		}
+		self.meet.record(content)
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Treelike, F: FilterBuilder<T>> DoubleEndedIterator for DFTP<T, F>
+where
+	F::Filter: DoubleEndedIterator,
+{
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let (root, filter) = (self.root.clone(), self.filter);
+		let build = |t: T, depth| filter.build(t.clone().content(), depth, t.children()).rev();
+		if self.meet.remaining.is_none() {
+			let consumed = self.consumed();
+			self.meet.remaining = Some(count(root.clone(), filter, 0) - consumed);
+			self.back.push((root.clone(), build(root, 0)));
+		}
+		if self.meet.done() {
+			return None;
+		}
+		let node = post_next(&mut self.back, build);
+		self.meet.record(node).map(T::content)
 	}
 }
 
@@ -433,7 +1376,7 @@ impl<T: Treelike, F: FilterBuilder<T>> BFT<T, F> {
 	}
 
 	fn push(&mut self, t: T, depth: usize) {
-		let filtered = self.filter.build(t.content(), depth, t.children());
+		let filtered = self.filter.build(t.clone().content(), depth, t.children());
 		self.queue.push_back((filtered, depth));
 	}
 }
@@ -446,10 +1389,124 @@ impl<T: Treelike, F: FilterBuilder<T>> Iterator for BFT<T, F> {
 
 		if let Some(child) = children.next() {
 			self.queue.push_front((children, depth));
-			self.push(child, depth + 1);
+			self.push(child.clone(), depth + 1);
 			Some(child.content())
 		} else {
 			self.next()
 		}
 	}
 }
+
+/// Iterative deepening depth-first search, see [iter_iddfs][Treelike::iter_iddfs].
+#[cfg(feature = "alloc")]
+pub struct IDDFS<T: Treelike, F: FilterBuilder<T>> {
+	root: T,
+	stack: Vec<F::Filter>,
+	filter: F,
+	/// the depth of the nodes returned in the current pass
+	limit: usize,
+	started: bool,
+	/// if any node at limit has children, so another pass is necessary
+	deeper: bool,
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Treelike, F: FilterBuilder<T>> IDDFS<T, F> {
+	fn new(root: T, filter: F) -> Self {
+		Self {
+			root,
+			stack: Vec::new(),
+			filter,
+			limit: 0,
+			started: false,
+			deeper: false,
+		}
+	}
+
+	/// The depth of the node that was returned last.
+	pub fn depth(&self) -> usize { self.limit }
+
+	/// Returns the content of t if it is at the current limit, else descends into it.
+	fn visit(&mut self, t: T, depth: usize) -> Option<T::Content> {
+		let mut children = self
+			.filter
+			.build(t.clone().content(), depth, t.clone().children());
+		if depth == self.limit {
+			self.deeper |= children.next().is_some();
+			Some(t.content())
+		} else {
+			self.stack.push(children);
+			None
+		}
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Treelike, F: FilterBuilder<T>> Iterator for IDDFS<T, F> {
+	type Item = T::Content;
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			let found = if let Some(children) = self.stack.last_mut() {
+				if let Some(child) = children.next() {
+					let depth = self.stack.len();
+					self.visit(child, depth)
+				} else {
+					self.stack.pop();
+					None
+				}
+			} else {
+				// the previous pass is done, start the next one
+				if self.started {
+					if !self.deeper {
+						return None;
+					}
+					self.limit += 1;
+				}
+				self.started = true;
+				self.deeper = false;
+				self.visit(self.root.clone(), 0)
+			};
+			if found.is_some() {
+				return found;
+			}
+		}
+	}
+}
+
+/// Iterator over the depth-layers of a tree, see [iter_levels][Treelike::iter_levels].
+#[cfg(feature = "alloc")]
+pub struct Levels<T: Treelike, F: FilterBuilder<T>> {
+	layer: Vec<T>,
+	depth: usize,
+	filter: F,
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Treelike, F: FilterBuilder<T>> Levels<T, F> {
+	fn new(treelike: T, filter: F) -> Self {
+		Self {
+			layer: alloc::vec![treelike],
+			depth: 0,
+			filter,
+		}
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Treelike, F: FilterBuilder<T>> Iterator for Levels<T, F> {
+	type Item = Vec<T::Content>;
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.layer.is_empty() {
+			return None;
+		}
+		let (depth, filter) = (self.depth, self.filter);
+		let layer = core::mem::take(&mut self.layer);
+		let contents = layer.iter().map(|node| node.clone().content()).collect();
+		self.layer = layer
+			.into_iter()
+			.flat_map(|node| filter.build(node.clone().content(), depth, node.children()))
+			.collect();
+		self.depth += 1;
+		Some(contents)
+	}
+}
@@ -81,7 +81,15 @@ fn callback_dft<T: TreelikeMut, CB: FnMut(&mut T::Content, usize), F: FilterBuil
 pub trait FilterBuilderMut<T: TreelikeMut> {
 	type Filter: Iterator<Item = T>;
 	fn build(&self, content: &T::Content, depth: usize, children: T::ChildIterator)
-		-> Self::Filter;
+	-> Self::Filter;
+}
+
+impl<T: TreelikeMut> FilterBuilderMut<T> for () {
+	type Filter = T::ChildIterator;
+
+	fn build(&self, _: &T::Content, _: usize, children: T::ChildIterator) -> Self::Filter {
+		children
+	}
 }
 
 // change cb to take &Content, mut trees can then make content be &mut realcontent
@@ -0,0 +1,117 @@
+use crate::Treelike;
+
+/// Extension of [Treelike] for trees whose nodes know their parent.
+///
+/// Only [parent][TreelikeParent::parent] needs to be implemented, everything else is provided.
+/// This allows walking upwards without re-traversing the tree from its root.
+///
+/// [PartialEq] is used to find a node among its parents children, so it has to compare node
+/// identity, not content. For reference-based nodes [core::ptr::eq] is usually what you want.
+/// [LinTree][crate::example::LinTree] compares its index and backing slice.
+pub trait TreelikeParent: Treelike + PartialEq {
+	/// Returns the parent of this node, or [None] if this is a root node.
+	fn parent(self) -> Option<Self>;
+
+	/// Returns an Iterator over all ancestors of this node, starting with its parent and ending
+	/// with the root.
+	fn ancestors(self) -> Ancestors<Self> {
+		Ancestors {
+			next: self.parent(),
+		}
+	}
+
+	/// Returns the root of the tree this node is part of.
+	fn root(self) -> Self { self.clone().ancestors().last().unwrap_or(self) }
+
+	/// Returns the amount of ancestors of this node, i.e. 0 for the root.
+	fn depth(self) -> usize { self.ancestors().count() }
+
+	/// Returns an Iterator over all children of this nodes parent, except this node itself.
+	fn siblings(self) -> Siblings<Self> {
+		Siblings {
+			children: self.clone().parent().map(Self::children),
+			node: self,
+		}
+	}
+
+	/// Returns the sibling directly right of this node.
+	fn next_sibling(self) -> Option<Self> {
+		let mut children = self.clone().parent()?.children();
+		children.find(|child| *child == self)?;
+		children.next()
+	}
+
+	/// Returns the sibling directly left of this node.
+	fn prev_sibling(self) -> Option<Self> {
+		let mut prev = None;
+		for child in self.clone().parent()?.children() {
+			if child == self {
+				return prev;
+			}
+			prev = Some(child);
+		}
+		None
+	}
+
+	/// True if this node is a proper ancestor of other.
+	// nodes are taken by value everywhere, they are cheap to clone
+	#[allow(clippy::wrong_self_convention)]
+	fn is_ancestor_of(self, other: Self) -> bool { other.ancestors().any(|a| a == self) }
+
+	/// Returns the lowest common ancestor of this node and other, or [None] if they are not part
+	/// of the same tree.
+	///
+	/// A node counts as its own ancestor here.
+	fn common_ancestor(self, other: Self) -> Option<Self> {
+		let (mut a, mut b) = (self, other);
+		let (mut depth_a, mut depth_b) = (a.clone().depth(), b.clone().depth());
+		while depth_a > depth_b {
+			a = a.parent()?;
+			depth_a -= 1;
+		}
+		while depth_b > depth_a {
+			b = b.parent()?;
+			depth_b -= 1;
+		}
+		while a != b {
+			a = a.parent()?;
+			b = b.parent()?;
+		}
+		Some(a)
+	}
+
+	/// Amount of edges on the path between this node and other, or [None] if they are not part
+	/// of the same tree.
+	fn distance(self, other: Self) -> Option<usize> {
+		let lca = self.clone().common_ancestor(other.clone())?.depth();
+		Some(self.depth() + other.depth() - 2 * lca)
+	}
+}
+
+/// Iterator over the ancestors of a node, see [ancestors][TreelikeParent::ancestors].
+pub struct Ancestors<T: TreelikeParent> {
+	next: Option<T>,
+}
+
+impl<T: TreelikeParent> Iterator for Ancestors<T> {
+	type Item = T;
+	fn next(&mut self) -> Option<T> {
+		let cur = self.next.take()?;
+		self.next = cur.clone().parent();
+		Some(cur)
+	}
+}
+
+/// Iterator over the siblings of a node, see [siblings][TreelikeParent::siblings].
+pub struct Siblings<T: TreelikeParent> {
+	children: Option<T::ChildIterator>,
+	node: T,
+}
+
+impl<T: TreelikeParent> Iterator for Siblings<T> {
+	type Item = T;
+	fn next(&mut self) -> Option<T> {
+		let node = &self.node;
+		self.children.as_mut()?.find(|child| child != node)
+	}
+}
@@ -0,0 +1,574 @@
+//! Trees whose nodes and children can fail to load.
+//!
+//! Trees backed by I/O or parsing can fail while listing the children of a node or while
+//! producing its content, which [Treelike][crate::Treelike] has no way to report.
+//! [TryTreelike] returns [Result]s instead.
+//!
+//! Errors are reported as a [PathError] that carries the depth of the failing node, and with
+//! the `alloc` feature its `TreePath`. The `try_callback_*` traversals stop at the first error
+//! and return it, the `try_iter_*` iterators yield errors in place of the failing nodes and
+//! continue with the rest of the tree. Children of nodes whose children could not be listed are
+//! skipped.
+#![cfg_attr(feature = "alloc", doc = "```")]
+#![cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+//! use treelike::TryTreelike;
+//!
+//! /// Nodes up to 3 have the children 2n and 2n + 1, multiples of 7 can't be read.
+//! #[derive(Clone)]
+//! struct Remote(u32);
+//!
+//! impl TryTreelike for Remote {
+//! 	type Content = u32;
+//! 	type Error = &'static str;
+//! 	type ChildIterator = std::vec::IntoIter<Result<Self, Self::Error>>;
+//!
+//! 	fn try_content(self) -> Result<u32, Self::Error> {
+//! 		if self.0 % 7 == 0 {
+//! 			Err("unreadable")
+//! 		} else {
+//! 			Ok(self.0)
+//! 		}
+//! 	}
+//!
+//! 	fn try_children(self) -> Result<Self::ChildIterator, Self::Error> {
+//! 		if self.0 > 3 {
+//! 			return Ok(vec![].into_iter());
+//! 		}
+//! 		Ok(vec![Ok(Remote(2 * self.0)), Ok(Remote(2 * self.0 + 1))].into_iter())
+//! 	}
+//! }
+//!
+//! // stop at the first error
+//! let first = Remote(1).try_callback_dft_pre(|_, _| {}).unwrap_err();
+//! assert_eq!(first.path().to_string(), "/1/1");
+//! assert_eq!(first.depth(), 2);
+//! assert_eq!(first.error(), &"unreadable");
+//!
+//! // or keep going and sort out the errors later
+//! let (ok, err): (Vec<_>, Vec<_>) = Remote(1).try_iter_bft().partition(Result::is_ok);
+//! assert_eq!(ok.len(), 6);
+//! assert_eq!(err.len(), 1);
+//! ```
+
+#[cfg(feature = "alloc")]
+use crate::path::TreePath;
+#[cfg(feature = "alloc")]
+use alloc::{collections::VecDeque, vec::Vec};
+use core::fmt;
+
+/// Like [Treelike][crate::Treelike], but producing the content or the children of a node may
+/// fail.
+///
+/// See the [module documentation][self] for an example.
+pub trait TryTreelike: Sized + Clone {
+	/// The content of the current node, see [Content][crate::Treelike::Content].
+	type Content;
+
+	/// Whatever can go wrong while loading the tree.
+	type Error;
+
+	/// Yields the children of a node, or an error for each child that could not be loaded.
+	type ChildIterator: Iterator<Item = Result<Self, Self::Error>>;
+
+	/// Has to produce this nodes [Content][TryTreelike::Content].
+	fn try_content(self) -> Result<Self::Content, Self::Error>;
+
+	/// Has to list this nodes direct children.
+	fn try_children(self) -> Result<Self::ChildIterator, Self::Error>;
+
+	/// Depth-first post-order traversal that stops at the first error.
+	fn try_callback_dft<CB: FnMut(Self::Content, usize)>(
+		self,
+		callback: CB,
+	) -> Result<(), PathError<Self::Error>> {
+		try_callback_dft(self, callback, false, 0)
+			.map(drop)
+			.map_err(PathError::finish)
+	}
+
+	/// Depth-first pre-order traversal that stops at the first error.
+	fn try_callback_dft_pre<CB: FnMut(Self::Content, usize)>(
+		self,
+		callback: CB,
+	) -> Result<(), PathError<Self::Error>> {
+		try_callback_dft(self, callback, true, 0)
+			.map(drop)
+			.map_err(PathError::finish)
+	}
+
+	/// Breadth-first traversal that stops at the first error.
+	///
+	/// Like [callback_bft][crate::Treelike::callback_bft] this does not allocate and instead
+	/// traverses the tree again for each depth, so nodes are loaded once per level below them.
+	/// `try_iter_bft` loads each node once.
+	fn try_callback_bft<CB: FnMut(Self::Content, usize)>(
+		self,
+		mut callback: CB,
+	) -> Result<(), PathError<Self::Error>> {
+		let mut depth = 0;
+		let mut count = 0;
+
+		loop {
+			let callback = |content| {
+				count += 1;
+				callback(content, depth)
+			};
+			let _ =
+				try_callback_bft(self.clone(), callback, depth, 0).map_err(PathError::finish)?;
+			if count == 0 {
+				return Ok(());
+			}
+			depth += 1;
+			count = 0;
+		}
+	}
+
+	/// Depth-first post-order iterator that yields errors and continues.
+	#[cfg(feature = "alloc")]
+	fn try_iter_dft(self) -> TryDFT<Self> { TryDFT::new(self, false) }
+
+	/// Depth-first pre-order iterator that yields errors and continues.
+	#[cfg(feature = "alloc")]
+	fn try_iter_dft_pre(self) -> TryDFT<Self> { TryDFT::new(self, true) }
+
+	/// Breadth-first iterator that yields errors and continues.
+	#[cfg(feature = "alloc")]
+	fn try_iter_bft(self) -> TryBFT<Self> { TryBFT::new(self) }
+}
+
+/// An error that happened at the node at path.
+///
+/// If listing the children of a node failed, path points to the node itself. If a single child
+/// could not be loaded it points to that child. Without the `alloc` feature only the depth of
+/// that node is known.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct PathError<E> {
+	#[cfg(feature = "alloc")]
+	path: TreePath,
+	depth: usize,
+	error: E,
+}
+
+impl<E> PathError<E> {
+	/// The path of the node the error happened at.
+	#[cfg(feature = "alloc")]
+	pub fn path(&self) -> &TreePath { &self.path }
+
+	/// The depth of the node the error happened at, the root has depth 0.
+	pub fn depth(&self) -> usize { self.depth }
+
+	/// The error returned by the tree.
+	pub fn error(&self) -> &E { &self.error }
+
+	/// Discards the position and returns the error of the tree.
+	pub fn into_error(self) -> E { self.error }
+
+	#[cfg(feature = "alloc")]
+	fn at(path: TreePath, error: E) -> Self {
+		PathError {
+			depth: path.depth(),
+			path,
+			error,
+		}
+	}
+
+	/// An error in a recursive traversal, the path is filled in while unwinding.
+	fn new(depth: usize, error: E) -> Self {
+		PathError {
+			#[cfg(feature = "alloc")]
+			path: TreePath::root(),
+			depth,
+			error,
+		}
+	}
+
+	/// Adds the index of the child the error came from, starting with the deepest one.
+	#[cfg(feature = "alloc")]
+	fn below(mut self, index: usize) -> Self {
+		self.path.push(index);
+		self
+	}
+
+	#[cfg(not(feature = "alloc"))]
+	fn below(self, _index: usize) -> Self { self }
+
+	/// Puts the indices added by [below][PathError::below] into root to node order.
+	#[cfg(feature = "alloc")]
+	fn finish(mut self) -> Self {
+		let mut path = Vec::from(self.path);
+		path.reverse();
+		self.path = path.into();
+		self
+	}
+
+	#[cfg(not(feature = "alloc"))]
+	fn finish(self) -> Self { self }
+}
+
+impl<E: fmt::Display> fmt::Display for PathError<E> {
+	#[cfg(feature = "alloc")]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{} at {}", self.error, self.path)
+	}
+
+	#[cfg(not(feature = "alloc"))]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{} at depth {}", self.error, self.depth)
+	}
+}
+
+#[cfg(feature = "std")]
+impl<E: std::error::Error + 'static> std::error::Error for PathError<E> {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> { Some(&self.error) }
+}
+
+fn try_callback_dft<T: TryTreelike, CB: FnMut(T::Content, usize)>(
+	t: T,
+	mut callback: CB,
+	pre: bool,
+	depth: usize,
+) -> Result<CB, PathError<T::Error>> {
+	let at = |error| PathError::new(depth, error);
+	if pre {
+		callback(t.clone().try_content().map_err(at)?, depth);
+	}
+
+	for (index, child) in t.clone().try_children().map_err(at)?.enumerate() {
+		callback = child
+			.map_err(|error| PathError::new(depth + 1, error))
+			.and_then(|child| try_callback_dft(child, callback, pre, depth + 1))
+			.map_err(|error| error.below(index))?;
+	}
+
+	if !pre {
+		callback(t.try_content().map_err(at)?, depth);
+	}
+	Ok(callback)
+}
+
+fn try_callback_bft<T: TryTreelike, CB: FnMut(T::Content)>(
+	t: T,
+	mut callback: CB,
+	limit: usize,
+	depth: usize,
+) -> Result<CB, PathError<T::Error>> {
+	let at = |error| PathError::new(depth, error);
+	if depth == limit {
+		callback(t.try_content().map_err(at)?);
+		return Ok(callback);
+	}
+
+	for (index, child) in t.try_children().map_err(at)?.enumerate() {
+		callback = child
+			.map_err(|error| PathError::new(depth + 1, error))
+			.and_then(|child| try_callback_bft(child, callback, limit, depth + 1))
+			.map_err(|error| error.below(index))?;
+	}
+
+	Ok(callback)
+}
+
+/// A node with its depth or an error, as produced by the traversals.
+#[cfg(feature = "alloc")]
+type Step<T> = Result<(<T as TryTreelike>::Content, usize), PathError<<T as TryTreelike>::Error>>;
+
+#[cfg(feature = "alloc")]
+struct Frame<T: TryTreelike> {
+	node: T,
+	/// listed when the first child is needed, so skipped nodes are never listed
+	listed: bool,
+	/// None if the children could not be listed
+	children: Option<T::ChildIterator>,
+	index: usize,
+}
+
+/// Fallible depth-first traversal, see [try_iter_dft][TryTreelike::try_iter_dft] and
+/// [try_iter_dft_pre][TryTreelike::try_iter_dft_pre].
+#[cfg(feature = "alloc")]
+pub struct TryDFT<T: TryTreelike> {
+	stack: Vec<Frame<T>>,
+	/// path of the node on top of the stack, or of next if it is set
+	path: TreePath,
+	next: Option<T>,
+	pre: bool,
+	/// true if the node on top of the stack was returned last and is not listed yet
+	skippable: bool,
+}
+
+#[cfg(feature = "alloc")]
+impl<T: TryTreelike> TryDFT<T> {
+	fn new(root: T, pre: bool) -> Self {
+		TryDFT {
+			stack: Vec::new(),
+			path: TreePath::root(),
+			next: Some(root),
+			pre,
+			skippable: false,
+		}
+	}
+
+	/// Don't visit the children of the node that was returned last, like
+	/// [DFTP::skip_subtree][crate::treelike::DFTP::skip_subtree].
+	///
+	/// Only works in pre-order. The children of the node are not even listed, so a `DirTree`
+	/// does not read skipped directories.
+	#[cfg_attr(feature = "std", doc = "```no_run")]
+	#[cfg_attr(not(feature = "std"), doc = "```ignore")]
+	/// use treelike::{TryTreelike, adapter::DirTree};
+	///
+	/// let mut iter = DirTree::new(".").try_iter_dft_pre();
+	/// while let Some(entry) = iter.next() {
+	/// 	if entry.is_ok_and(|entry| entry.name() == Some("target".as_ref())) {
+	/// 		iter.skip_subtree();
+	/// 	}
+	/// }
+	/// ```
+	pub fn skip_subtree(&mut self) {
+		if !core::mem::take(&mut self.skippable) {
+			return;
+		}
+		if let Some(frame) = self.stack.last_mut() {
+			frame.listed = true;
+		}
+	}
+
+	fn at(&self, error: T::Error) -> PathError<T::Error> { PathError::at(self.path.clone(), error) }
+
+	fn content(&self, node: T) -> Step<T> {
+		node.try_content()
+			.map(|content| (content, self.path.depth()))
+			.map_err(|error| self.at(error))
+	}
+
+	fn step(&mut self) -> Option<Step<T>> {
+		self.skippable = false;
+		loop {
+			if let Some(node) = self.next.take() {
+				self.stack.push(Frame {
+					node: node.clone(),
+					listed: false,
+					children: None,
+					index: 0,
+				});
+				if self.pre {
+					self.skippable = true;
+					return Some(self.content(node));
+				}
+			}
+
+			let frame = self.stack.last_mut()?;
+			if !frame.listed {
+				frame.listed = true;
+				match frame.node.clone().try_children() {
+					Ok(children) => frame.children = Some(children),
+					Err(error) => return Some(Err(self.at(error))),
+				}
+			}
+			let index = frame.index;
+			match frame.children.as_mut().and_then(Iterator::next) {
+				Some(child) => {
+					frame.index += 1;
+					self.path.push(index);
+					match child {
+						Ok(child) => self.next = Some(child),
+						Err(error) => {
+							let error = self.at(error);
+							self.path.pop();
+							return Some(Err(error));
+						},
+					}
+				},
+				None => {
+					let node = self.stack.pop()?.node;
+					let content = if self.pre {
+						None
+					} else {
+						Some(self.content(node))
+					};
+					self.path.pop();
+					if content.is_some() {
+						return content;
+					}
+				},
+			}
+		}
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<T: TryTreelike> Iterator for TryDFT<T> {
+	type Item = Result<T::Content, PathError<T::Error>>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.step()
+			.map(|item| item.map(|(content, _depth)| content))
+	}
+}
+
+/// Fallible breadth-first traversal, see [try_iter_bft][TryTreelike::try_iter_bft].
+#[cfg(feature = "alloc")]
+pub struct TryBFT<T: TryTreelike> {
+	/// errors are queued in place of the children they are about, so they come out in order
+	queue: VecDeque<Queued<T>>,
+}
+
+/// A node with its path or an error, waiting in the queue of a [TryBFT].
+#[cfg(feature = "alloc")]
+type Queued<T> = Result<(T, TreePath), PathError<<T as TryTreelike>::Error>>;
+
+#[cfg(feature = "alloc")]
+impl<T: TryTreelike> TryBFT<T> {
+	fn new(root: T) -> Self {
+		let mut queue = VecDeque::new();
+		queue.push_back(Ok((root, TreePath::root())));
+		TryBFT { queue }
+	}
+
+	fn step(&mut self) -> Option<Step<T>> {
+		let (node, path) = match self.queue.pop_front()? {
+			Ok(entry) => entry,
+			Err(error) => return Some(Err(error)),
+		};
+		match node.clone().try_children() {
+			Ok(children) => {
+				for (index, child) in children.enumerate() {
+					let path = path.child(index);
+					self.queue.push_back(match child {
+						Ok(child) => Ok((child, path)),
+						Err(error) => Err(PathError::at(path, error)),
+					});
+				}
+			},
+			Err(error) => self
+				.queue
+				.push_back(Err(PathError::at(path.clone(), error))),
+		}
+		let depth = path.depth();
+		Some(
+			node.try_content()
+				.map(|content| (content, depth))
+				.map_err(|error| PathError::at(path, error)),
+		)
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<T: TryTreelike> Iterator for TryBFT<T> {
+	type Item = Result<T::Content, PathError<T::Error>>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.step()
+			.map(|item| item.map(|(content, _depth)| content))
+	}
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn try_treelike_test() {
+	use crate::example::OwningTree;
+
+	/// "content" can't be read, "list" can't list its children and "child" can't be loaded.
+	#[derive(Clone)]
+	struct Flaky<'a>(&'a OwningTree<&'static str>);
+
+	impl<'a> TryTreelike for Flaky<'a> {
+		type Content = &'static str;
+		type Error = &'static str;
+		type ChildIterator = alloc::vec::IntoIter<Result<Self, Self::Error>>;
+
+		fn try_content(self) -> Result<Self::Content, Self::Error> {
+			match self.0.content {
+				"content" => Err("content"),
+				name => Ok(name),
+			}
+		}
+
+		fn try_children(self) -> Result<Self::ChildIterator, Self::Error> {
+			if self.0.content == "list" {
+				return Err("list");
+			}
+			let children = self.0.children.iter().map(|child| match child.content {
+				"child" => Err("child"),
+				_ => Ok(Flaky(child)),
+			});
+			Ok(children.collect::<Vec<_>>().into_iter())
+		}
+	}
+
+	let tree: OwningTree<&str> =
+		crate::tree!["a" => ["b" => ["child", "c"], "list" => ["d"], "content"]];
+	let root = Flaky(&tree);
+	let show = |item: Result<&str, PathError<&str>>| match item {
+		Ok(name) => name.into(),
+		Err(e) => e.to_string(),
+	};
+
+	let pre: Vec<String> = root.clone().try_iter_dft_pre().map(show).collect();
+	assert_eq!(pre, [
+		"a",
+		"b",
+		"child at /0/0",
+		"c",
+		"list",
+		"list at /1",
+		"content at /2"
+	]);
+	// skipped nodes are not listed, so their errors don't show up either
+	let mut iter = root.clone().try_iter_dft_pre();
+	let mut pruned = Vec::new();
+	while let Some(item) = iter.next() {
+		let item = show(item);
+		if item == "b" || item == "list" {
+			iter.skip_subtree();
+		}
+		pruned.push(item);
+	}
+	assert_eq!(pruned, ["a", "b", "list", "content at /2"]);
+
+	let post: Vec<String> = root.clone().try_iter_dft().map(show).collect();
+	assert_eq!(post, [
+		"child at /0/0",
+		"c",
+		"b",
+		"list at /1",
+		"list",
+		"content at /2",
+		"a"
+	]);
+	let bft: Vec<String> = root.clone().try_iter_bft().map(show).collect();
+	assert_eq!(bft, [
+		"a",
+		"b",
+		"list",
+		"content at /2",
+		"child at /0/0",
+		"c",
+		"list at /1"
+	]);
+
+	let mut visited = Vec::new();
+	let first = root
+		.clone()
+		.try_callback_dft_pre(|name, depth| visited.push((name, depth)));
+	assert_eq!(visited, [("a", 0), ("b", 1)]);
+	let first = first.unwrap_err();
+	assert_eq!(first.path(), &TreePath::from(vec![0, 0]));
+	assert_eq!(first.depth(), 2);
+
+	// the callbacks stop at the first error the iterators yield
+	fn first_error<C, E>(mut iter: impl Iterator<Item = Result<C, E>>) -> Result<(), E> {
+		iter.find_map(Result::err).map_or(Ok(()), Err)
+	}
+	let dft_pre = root.clone().try_callback_dft_pre(|_, _| {});
+	assert_eq!(dft_pre, first_error(root.clone().try_iter_dft_pre()));
+	let dft = root.clone().try_callback_dft(|_, _| {});
+	assert_eq!(dft, first_error(root.clone().try_iter_dft()));
+	let bft = root.clone().try_callback_bft(|_, _| {});
+	assert_eq!(bft, first_error(root.clone().try_iter_bft()));
+
+	let ok = Flaky(&tree.children[0].children[1]).try_callback_bft(|name, depth| {
+		assert_eq!((name, depth), ("c", 0));
+	});
+	assert_eq!(ok, Ok(()));
+}
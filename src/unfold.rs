@@ -0,0 +1,136 @@
+//! Trees that are generated while they are traversed.
+//!
+//! Game states, search spaces and many number trees are never stored anywhere, each node is
+//! computed from its parent. [Unfold] describes such a tree by a seed and a function returning
+//! the seeds of the children, so all traversals and searches can run on it without building
+//! the tree first.
+//!
+//! Generated trees are often infinite. Only descend as deep as needed by limiting the traversal
+//! with [MaxDepth][crate::treelike::MaxDepth] or by searching with `iter_iddfs`, which goes one
+//! level deeper at a time.
+//!
+//! ```
+//! use treelike::{Treelike, treelike::MaxDepth, unfold::Unfold};
+//!
+//! // every number has the children 2n and 2n + 1, which enumerates all positive numbers
+//! let numbers = Unfold::new(1u32, |n: &u32| [2 * n, 2 * n + 1]);
+//!
+//! let mut pre = Vec::new();
+//! numbers.callback_dft_pre(|n, _depth| pre.push(n), MaxDepth::new(2));
+//! assert_eq!(pre, [1, 2, 4, 5, 3, 6, 7]);
+//! let mut bft = Vec::new();
+//! numbers.callback_bft_filtered(|n, _depth| bft.push(n), MaxDepth::new(2));
+//! assert_eq!(bft, [1, 2, 3, 4, 5, 6, 7]);
+//! ```
+
+use crate::Treelike;
+
+/// A tree generated from a seed by a function that returns the seeds of the children.
+///
+/// The content of a node is its seed. Nodes are cloned during traversals, so the seed should be
+/// cheap to [Clone] and the function has to be [Copy], which closures are if everything they
+/// capture is. See the [module documentation][self] for examples.
+///
+/// Searching the shortest way to reach 10 from 1 by doubling and adding one:
+#[cfg_attr(feature = "alloc", doc = "```")]
+#[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+/// use treelike::{Treelike, unfold::Unfold};
+///
+/// let moves = Unfold::new(1u32, |n: &u32| [n * 2, n + 1]);
+///
+/// // iterative deepening finds the shallowest solution first, even in an infinite tree
+/// let mut search = moves.iter_iddfs(());
+/// search.find(|n| *n == 10).unwrap();
+/// // 1 -> 2 -> 4 -> 5 -> 10
+/// assert_eq!(search.depth(), 4);
+/// ```
+#[derive(Debug)]
+pub struct Unfold<S, F> {
+	seed: S,
+	children: F,
+}
+
+impl<S, I: IntoIterator<Item = S>, F: Fn(&S) -> I + Copy> Unfold<S, F> {
+	pub fn new(seed: S, children: F) -> Self { Unfold { seed, children } }
+}
+
+impl<S: Copy, F: Copy> Copy for Unfold<S, F> {}
+
+impl<S: Clone, F: Copy> Clone for Unfold<S, F> {
+	fn clone(&self) -> Self {
+		Unfold {
+			seed: self.seed.clone(),
+			children: self.children,
+		}
+	}
+}
+
+impl<S: Clone, I: IntoIterator<Item = S>, F: Fn(&S) -> I + Copy> Treelike for Unfold<S, F> {
+	type Content = S;
+
+	fn content(self) -> Self::Content { self.seed }
+
+	type ChildIterator = UnfoldChildren<I::IntoIter, F>;
+
+	fn children(self) -> Self::ChildIterator {
+		UnfoldChildren {
+			seeds: (self.children)(&self.seed).into_iter(),
+			children: self.children,
+		}
+	}
+}
+
+/// Iterator over the children of an [Unfold].
+///
+/// Can be iterated from the back if the seeds can.
+#[derive(Debug)]
+pub struct UnfoldChildren<I, F> {
+	seeds: I,
+	children: F,
+}
+
+impl<I: Iterator, F: Copy> Iterator for UnfoldChildren<I, F> {
+	type Item = Unfold<I::Item, F>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let children = self.children;
+		self.seeds.next().map(|seed| Unfold { seed, children })
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) { self.seeds.size_hint() }
+}
+
+impl<I: DoubleEndedIterator, F: Copy> DoubleEndedIterator for UnfoldChildren<I, F> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let children = self.children;
+		self.seeds.next_back().map(|seed| Unfold { seed, children })
+	}
+}
+
+impl<I: ExactSizeIterator, F: Copy> ExactSizeIterator for UnfoldChildren<I, F> {}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn unfold_test() {
+	use crate::{example::LinTree, treelike::MaxDepth};
+
+	// the implicit tree of a LinTree, generated from the indices
+	let base: Vec<usize> = (0..10).collect();
+	let lin = LinTree::new(0, &base);
+	let len = base.len();
+	let indices = Unfold::new(0, move |i: &usize| {
+		(2 * i + 1..2 * i + 3).filter(move |c| *c < len)
+	});
+
+	assert!(lin.iter_dft(()).copied().eq(indices.iter_dft(())));
+	assert!(lin.iter_dft_pre(()).copied().eq(indices.iter_dft_pre(())));
+	assert!(lin.iter_bft(()).copied().eq(indices.iter_bft(())));
+	assert!(lin.iter_dft_rev(()).copied().eq(indices.iter_dft_rev(())));
+	assert_eq!(indices.last(), 6);
+
+	// an infinite tree with an unbounded number of children
+	let ranges = Unfold::new(1usize, |n: &usize| 1..=*n + 1);
+	assert_eq!(ranges.iter_dft(MaxDepth::new(2)).count(), 1 + 2 + 5);
+	assert_eq!(ranges.level(3).len(), 14);
+	assert_eq!(ranges.iter_iddfs(()).nth(3), Some(1));
+}
@@ -0,0 +1,169 @@
+//! Reusable traversals packaged as types.
+//!
+//! Instead of passing a callback and a filter to one of the `callback_*` functions you can
+//! implement [TreeVisitor] and run it with [visit][Treelike::visit]. The visitor decides at each
+//! node whether to descend, skip the children or stop, and can keep whatever state it needs.
+
+use crate::Treelike;
+
+/// What a [TreeVisitor] wants to happen after one of its methods was called.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum VisitControl {
+	/// Continue normally, visiting the children of the node.
+	Descend,
+	/// Continue without visiting the children of the node.
+	/// Only meaningful when returned from [enter][TreeVisitor::enter],
+	/// otherwise it is the same as [Descend][VisitControl::Descend].
+	SkipChildren,
+	/// Abort the whole traversal.
+	Stop,
+}
+
+/// A depth-first traversal with hooks on the way down and up.
+///
+/// All methods have empty default bodies, so you only need to implement what you need.
+///
+/// Nodes with children are passed to [enter][TreeVisitor::enter] before and to
+/// [leave][TreeVisitor::leave] after their children. Nodes without children are only passed to
+/// [leaf][TreeVisitor::leaf].
+///
+/// # Example
+/// ```
+/// # use treelike::example::LinTree;
+/// # use treelike::Treelike;
+/// use treelike::visitor::{TreeVisitor, VisitControl};
+///
+/// /// Sums up contents, ignoring everything below the content 1.
+/// struct Sum(usize);
+///
+/// impl<'a> TreeVisitor<LinTree<'a, usize>> for Sum {
+/// 	fn enter(&mut self, node: LinTree<'a, usize>, _depth: usize) -> VisitControl {
+/// 		self.0 += node.content();
+/// 		if *node.content() == 1 {
+/// 			VisitControl::SkipChildren
+/// 		} else {
+/// 			VisitControl::Descend
+/// 		}
+/// 	}
+///
+/// 	fn leaf(&mut self, node: LinTree<'a, usize>, _depth: usize) -> VisitControl {
+/// 		self.0 += node.content();
+/// 		VisitControl::Descend
+/// 	}
+/// }
+///
+/// let base = [0, 1, 2, 3, 4, 5];
+/// let mut sum = Sum(0);
+/// LinTree::new(0, &base).visit(&mut sum);
+/// assert_eq!(sum.0, 0 + 1 + 2 + 5);
+/// ```
+pub trait TreeVisitor<T: Treelike> {
+	/// Called before visiting the children of a node.
+	fn enter(&mut self, _node: T, _depth: usize) -> VisitControl { VisitControl::Descend }
+
+	/// Called after visiting the children of a node.
+	///
+	/// Also called if [enter][TreeVisitor::enter] returned
+	/// [SkipChildren][VisitControl::SkipChildren].
+	fn leave(&mut self, _node: T, _depth: usize) -> VisitControl { VisitControl::Descend }
+
+	/// Called instead of [enter][TreeVisitor::enter] and [leave][TreeVisitor::leave] for nodes
+	/// without children.
+	fn leaf(&mut self, _node: T, _depth: usize) -> VisitControl { VisitControl::Descend }
+}
+
+/// Returns false if the traversal was stopped.
+pub(crate) fn visit<T: Treelike, V: TreeVisitor<T> + ?Sized>(
+	t: T,
+	visitor: &mut V,
+	depth: usize,
+) -> bool {
+	let mut children = t.clone().children().peekable();
+	if children.peek().is_none() {
+		return visitor.leaf(t, depth) != VisitControl::Stop;
+	}
+
+	match visitor.enter(t.clone(), depth) {
+		VisitControl::Stop => return false,
+		VisitControl::SkipChildren => (),
+		VisitControl::Descend => {
+			for child in children {
+				if !visit(child, visitor, depth + 1) {
+					return false;
+				}
+			}
+		},
+	}
+
+	visitor.leave(t, depth) != VisitControl::Stop
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn visitor_test() {
+	use crate::example::LinTree;
+	use alloc::{string::String, vec::Vec};
+
+	struct Record {
+		log: Vec<String>,
+		stop_at: usize,
+	}
+
+	impl<'a> TreeVisitor<LinTree<'a, usize>> for Record {
+		fn enter(&mut self, node: LinTree<'a, usize>, depth: usize) -> VisitControl {
+			self.log
+				.push(alloc::format!("enter {} {}", node.content(), depth));
+			if *node.content() == 2 {
+				VisitControl::SkipChildren
+			} else {
+				VisitControl::Descend
+			}
+		}
+
+		fn leave(&mut self, node: LinTree<'a, usize>, depth: usize) -> VisitControl {
+			self.log
+				.push(alloc::format!("leave {} {}", node.content(), depth));
+			VisitControl::Descend
+		}
+
+		fn leaf(&mut self, node: LinTree<'a, usize>, depth: usize) -> VisitControl {
+			self.log
+				.push(alloc::format!("leaf {} {}", node.content(), depth));
+			if *node.content() == self.stop_at {
+				VisitControl::Stop
+			} else {
+				VisitControl::Descend
+			}
+		}
+	}
+
+	let base = [0, 1, 2, 3, 4, 5, 6];
+	let root = LinTree::new(0, &base);
+
+	let mut record = Record {
+		log: Vec::new(),
+		stop_at: usize::MAX,
+	};
+	assert!(root.visit(&mut record));
+	assert_eq!(record.log, [
+		"enter 0 0",
+		"enter 1 1",
+		"leaf 3 2",
+		"leaf 4 2",
+		"leave 1 1",
+		"enter 2 1",
+		"leave 2 1",
+		"leave 0 0",
+	]);
+
+	let mut record = Record {
+		log: Vec::new(),
+		stop_at: 3,
+	};
+	assert!(!root.visit(&mut record));
+	assert_eq!(record.log, ["enter 0 0", "enter 1 1", "leaf 3 2"]);
+
+	struct Nothing;
+	impl<T: Treelike> TreeVisitor<T> for Nothing {}
+	assert!(root.visit(&mut Nothing));
+}
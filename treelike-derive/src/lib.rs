@@ -0,0 +1,198 @@
+#![allow(clippy::tabs_in_doc_comments)]
+
+//! Derive macros for [treelike](https://docs.rs/treelike).
+//!
+//! Mark the field holding the content with `#[treelike(content)]` and the field holding the
+//! children with `#[treelike(children)]`. `#[derive(Treelike)]` then implements `Treelike` for
+//! references to the struct, `#[derive(TreelikeMut)]` implements `TreelikeMut` for mutable
+//! references.
+//!
+//! The children field can be anything that implements `treelike::container::ChildContainer`
+//! (and `ChildContainerMut` for `TreelikeMut`), for example `Vec<Self>`,
+//! `[Option<Box<Self>>; 2]` or `Option<&'a Self>`.
+//!
+//! ```
+//! use treelike::Treelike;
+//! use treelike_derive::Treelike;
+//!
+//! #[derive(Treelike)]
+//! struct Node {
+//! 	#[treelike(content)]
+//! 	name: &'static str,
+//! 	#[treelike(children)]
+//! 	children: Vec<Node>,
+//! }
+//!
+//! let tree = Node {
+//! 	name: "root",
+//! 	children: vec![Node {
+//! 		name: "leaf",
+//! 		children: vec![],
+//! 	}],
+//! };
+//! let names: Vec<_> = tree.iter_dft_pre(()).collect();
+//! assert_eq!(names, [&"root", &"leaf"]);
+//! ```
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{
+	Data, DeriveInput, Error, Fields, Lifetime, Member, Type, parse_macro_input, parse_quote,
+	visit_mut::VisitMut,
+};
+
+#[proc_macro_derive(Treelike, attributes(treelike))]
+pub fn derive_treelike(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = parse_macro_input!(input as DeriveInput);
+	expand(&input, false)
+		.unwrap_or_else(Error::into_compile_error)
+		.into()
+}
+
+#[proc_macro_derive(TreelikeMut, attributes(treelike))]
+pub fn derive_treelike_mut(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = parse_macro_input!(input as DeriveInput);
+	expand(&input, true)
+		.unwrap_or_else(Error::into_compile_error)
+		.into()
+}
+
+/// A field marked with one of the attributes.
+struct Marked {
+	member: Member,
+	ty: Type,
+}
+
+fn expand(input: &DeriveInput, mutable: bool) -> syn::Result<TokenStream> {
+	let fields = match &input.data {
+		Data::Struct(data) => &data.fields,
+		_ => {
+			return Err(Error::new_spanned(
+				input,
+				"Treelike can only be derived for structs",
+			));
+		},
+	};
+	let (content, children) = find_fields(input, fields)?;
+
+	let name = &input.ident;
+	let (_, ty_generics, _) = input.generics.split_for_impl();
+	let node: Type = parse_quote!(#name #ty_generics);
+
+	// the fields may mention Self, which is the reference in the impl
+	let mut replace = ReplaceSelf(&node);
+	let (mut content_ty, mut children_ty) = (content.ty, children.ty);
+	replace.visit_type_mut(&mut content_ty);
+	replace.visit_type_mut(&mut children_ty);
+
+	let lifetime = Lifetime::new("'__treelike", Span::call_site());
+	let mut generics = input.generics.clone();
+	generics.params.insert(0, parse_quote!(#lifetime));
+	let (container, iter, reference) = if mutable {
+		(
+			quote!(::treelike::container::ChildContainerMut),
+			quote!(IterMut),
+			quote!(&#lifetime mut),
+		)
+	} else {
+		(
+			quote!(::treelike::container::ChildContainer),
+			quote!(Iter),
+			quote!(&#lifetime),
+		)
+	};
+	generics
+		.make_where_clause()
+		.predicates
+		.push(parse_quote!(#children_ty: #container<#lifetime, #node>));
+	let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+	let (content_member, children_member) = (content.member, children.member);
+	let child_iterator = quote!(<#children_ty as #container<#lifetime, #node>>::#iter);
+	Ok(if mutable {
+		quote! {
+			impl #impl_generics ::treelike::TreelikeMut for #reference #node #where_clause {
+				type Content = #reference #content_ty;
+
+				type ChildIterator = #child_iterator;
+
+				fn into_tuple(self) -> (Self::Content, Self::ChildIterator) {
+					(
+						&mut self.#content_member,
+						#container::child_iter_mut(&mut self.#children_member),
+					)
+				}
+			}
+		}
+	} else {
+		quote! {
+			impl #impl_generics ::treelike::Treelike for #reference #node #where_clause {
+				type Content = #reference #content_ty;
+
+				fn content(self) -> Self::Content { &self.#content_member }
+
+				type ChildIterator = #child_iterator;
+
+				fn children(self) -> Self::ChildIterator {
+					#container::child_iter(&self.#children_member)
+				}
+			}
+		}
+	})
+}
+
+fn find_fields(input: &DeriveInput, fields: &Fields) -> syn::Result<(Marked, Marked)> {
+	let (mut content, mut children) = (None, None);
+	for (index, field) in fields.iter().enumerate() {
+		let member = match &field.ident {
+			Some(ident) => Member::Named(ident.clone()),
+			None => Member::Unnamed(index.into()),
+		};
+		for attr in field.attrs.iter().filter(|a| a.path().is_ident("treelike")) {
+			attr.parse_nested_meta(|meta| {
+				let slot = if meta.path.is_ident("content") {
+					&mut content
+				} else if meta.path.is_ident("children") {
+					&mut children
+				} else {
+					return Err(meta.error("expected `content` or `children`"));
+				};
+				if slot.is_some() {
+					return Err(meta.error("only one field can be marked like this"));
+				}
+				*slot = Some(Marked {
+					member: member.clone(),
+					ty: field.ty.clone(),
+				});
+				Ok(())
+			})?;
+		}
+	}
+
+	match (content, children) {
+		(Some(content), Some(children)) => Ok((content, children)),
+		(None, _) => Err(Error::new_spanned(
+			input,
+			"mark the content field with #[treelike(content)]",
+		)),
+		(_, None) => Err(Error::new_spanned(
+			input,
+			"mark the children field with #[treelike(children)]",
+		)),
+	}
+}
+
+/// Replaces `Self` with the type of the node.
+struct ReplaceSelf<'a>(&'a Type);
+
+impl VisitMut for ReplaceSelf<'_> {
+	fn visit_type_mut(&mut self, ty: &mut Type) {
+		if let Type::Path(path) = ty {
+			if path.qself.is_none() && path.path.is_ident("Self") {
+				*ty = self.0.clone();
+				return;
+			}
+		}
+		syn::visit_mut::visit_type_mut(self, ty);
+	}
+}
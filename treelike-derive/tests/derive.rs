@@ -0,0 +1,112 @@
+// only the methods are needed, with the derive feature these names also carry the macros
+use treelike::{Treelike as _, TreelikeMut as _};
+use treelike_derive::{Treelike, TreelikeMut};
+
+#[derive(Treelike, TreelikeMut)]
+struct VecTree<T> {
+	#[treelike(content)]
+	content: T,
+	#[treelike(children)]
+	children: Vec<Self>,
+}
+
+#[derive(Treelike, TreelikeMut)]
+struct BoxTree {
+	#[treelike(children)]
+	children: [Option<Box<BoxTree>>; 2],
+	#[treelike(content)]
+	content: usize,
+}
+
+#[derive(Treelike)]
+struct BorrowTree<'a, T> {
+	#[treelike(content)]
+	content: T,
+	#[treelike(children)]
+	children: [Option<&'a Self>; 2],
+}
+
+#[derive(Treelike)]
+struct List<'a>(
+	#[treelike(content)] u8,
+	#[treelike(children)] Option<&'a List<'a>>,
+);
+
+#[test]
+fn vec_tree() {
+	let mut tree = VecTree {
+		content: 0,
+		children: vec![
+			VecTree {
+				content: 1,
+				children: vec![],
+			},
+			VecTree {
+				content: 2,
+				children: vec![VecTree {
+					content: 3,
+					children: vec![],
+				}],
+			},
+		],
+	};
+	let post: Vec<_> = tree.iter_dft(()).cloned().collect();
+	assert_eq!(post, [1, 3, 2, 0]);
+	// the exact iterator type is kept, slice iterators can be reversed
+	let mirrored: Vec<_> = tree.iter_dft_rev(()).cloned().collect();
+	assert_eq!(mirrored, [3, 2, 1, 0]);
+
+	(&mut tree).callback_dft_pre(|content, depth| **content += depth * 10, ());
+	let pre: Vec<_> = tree.iter_dft_pre(()).cloned().collect();
+	assert_eq!(pre, [0, 11, 12, 23]);
+}
+
+#[test]
+fn box_tree() {
+	let mut tree = BoxTree {
+		children: [
+			None,
+			Some(Box::new(BoxTree {
+				children: [
+					Some(Box::new(BoxTree {
+						children: [None, None],
+						content: 2,
+					})),
+					None,
+				],
+				content: 1,
+			})),
+		],
+		content: 0,
+	};
+	assert_eq!(tree.iter_bft(()).cloned().collect::<Vec<_>>(), [0, 1, 2]);
+
+	(&mut tree).callback_dft(|content, _| **content *= 2, ());
+	assert_eq!(*tree.last(), 4);
+}
+
+#[test]
+fn borrow_tree() {
+	let c = BorrowTree {
+		content: "c",
+		children: [None; 2],
+	};
+	let b = BorrowTree {
+		content: "b",
+		children: [Some(&c), None],
+	};
+	let a = BorrowTree {
+		content: "a",
+		children: [Some(&b), Some(&c)],
+	};
+	let contents: Vec<_> = a.iter_dft_pre(()).cloned().collect();
+	assert_eq!(contents, ["a", "b", "c", "c"]);
+}
+
+#[test]
+fn tuple_list() {
+	let tail = List(2, None);
+	let list = List(0, Some(&List(1, Some(&tail))));
+	assert_eq!(*list.first(), 2);
+	assert_eq!(list.iter_dft_pre(()).count(), 3);
+}